@@ -1,5 +1,5 @@
-use crate::types::{DataKey, Error};
-use soroban_sdk::{panic_with_error, Address, Env};
+use crate::types::{DataKey, Error, Role};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
 
 /// Check if the caller is the admin and require authentication
 ///
@@ -25,3 +25,39 @@ pub fn check_admin(env: &Env) -> Address {
 
     admin
 }
+
+/// Read the accounts explicitly granted `role`. The admin is not listed but
+/// implicitly holds every role.
+pub fn get_role_members(env: &Env, role: Role) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RoleMembers(role))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Check whether `account` holds `role`, either as the admin or by grant
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+    if admin.as_ref() == Some(account) {
+        return true;
+    }
+    get_role_members(env, role).contains(account)
+}
+
+/// Require `caller` to hold `role` and authenticate it
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `caller` - The address claiming the role
+/// * `role` - The narrowest role allowed to perform the action
+///
+/// # Panics
+/// Panics with Error::MissingRole if `caller` is neither the admin nor a
+/// member of `role`
+pub fn check_role(env: &Env, caller: &Address, role: Role) {
+    caller.require_auth();
+
+    if !has_role(env, role, caller) {
+        panic_with_error!(env, Error::MissingRole);
+    }
+}
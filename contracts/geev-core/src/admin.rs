@@ -1,6 +1,40 @@
-use crate::types::{DataKey, Error, GiveawayStatus, HelpRequestStatus};
+use crate::access::{check_role, get_role_members, has_role};
+use crate::escrow::get_liabilities;
+use crate::giveaway::DEFAULT_FEE_BPS;
+use crate::multisig::ensure_single_key_allowed;
+use crate::participants::remove_disqualification;
+use crate::pause;
+use crate::profile::ProfileContract;
+use crate::timelock::{ensure_not_timelocked, timelock_delay};
+use crate::tokens::{allowed_tokens, store_allowed_tokens};
+use crate::ttl::{extend_content_ttl, extend_instance, extend_persistent, ttl_config};
+use crate::types::{
+    DataKey, Disqualification, EntryKey, Error, FeeTier, Giveaway, GiveawayStatus,
+    HelpRequestStatus, PauseMode, PauseModule, PendingAdmin, Role, Solvency, TtlConfig,
+};
 use crate::{access::check_admin, types::HelpRequest};
-use soroban_sdk::{contract, contractevent, contractimpl, panic_with_error, token, Address, Env};
+use soroban_sdk::{
+    contract, contractevent, contractimpl, panic_with_error, token, Address, Env, Vec,
+};
+
+/// Upper bound for any fee rate, in basis points (100%).
+const MAX_FEE_BPS: u32 = 10_000;
+
+/// How long a proposed admin has to call `accept_admin` (7 days).
+const ADMIN_PROPOSAL_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// The rate `tiers` charges a creator with `reputation`: that of the highest
+/// tier reached, or `base_fee_bps` below the first.
+pub(crate) fn scheduled_fee_bps(tiers: &Vec<FeeTier>, base_fee_bps: u32, reputation: u64) -> u32 {
+    let mut fee_bps = base_fee_bps;
+    for tier in tiers.iter() {
+        if reputation < tier.min_reputation {
+            break;
+        }
+        fee_bps = tier.fee_bps;
+    }
+    fee_bps
+}
 
 #[contract]
 pub struct AdminContract;
@@ -12,6 +46,42 @@ pub struct EmergencyWithdraw {
     to: Address,
 }
 
+#[contractevent]
+pub struct EmergencyStatusChanged {
+    active: bool,
+}
+
+#[contractevent]
+pub struct RoleGranted {
+    #[topic]
+    role: Role,
+    #[topic]
+    account: Address,
+}
+
+#[contractevent]
+pub struct RoleRevoked {
+    #[topic]
+    role: Role,
+    #[topic]
+    account: Address,
+}
+
+#[contractevent]
+pub struct PauseModeChanged {
+    #[topic]
+    caller: Address,
+    mode: PauseMode,
+}
+
+#[contractevent]
+pub struct ModulePauseChanged {
+    #[topic]
+    caller: Address,
+    module: PauseModule,
+    paused: bool,
+}
+
 #[contractevent]
 pub struct TokenAdded {
     token: Address,
@@ -35,8 +105,58 @@ pub struct AppealResolved {
     restored: bool,
 }
 
-/// Emitted when admin control is transferred. Topics are `admin`, `transfer`,
-/// plus the previous admin address; data is `[new_admin]`.
+#[contractevent]
+pub struct DisqualificationAppealResolved {
+    #[topic]
+    giveaway_id: u64,
+    participant: Address,
+    reinstated: bool,
+}
+
+#[contractevent]
+pub struct FeeUpdated {
+    fee_bps: u32,
+}
+
+#[contractevent]
+pub struct FeeScheduleUpdated {
+    tiers: Vec<FeeTier>,
+}
+
+#[contractevent]
+pub struct FeeOverrideSet {
+    #[topic]
+    creator: Address,
+    fee_bps: u32,
+}
+
+#[contractevent]
+pub struct FeeOverrideRemoved {
+    #[topic]
+    creator: Address,
+}
+
+/// Emitted when the admin proposes a successor. Topics are `admin`, `propose`,
+/// plus the current admin address; data is `[new_admin, expires_at]`.
+#[contractevent(topics = ["admin", "propose"], data_format = "vec")]
+pub struct AdminProposed {
+    #[topic]
+    current_admin: Address,
+    new_admin: Address,
+    expires_at: u64,
+}
+
+/// Emitted when a pending admin proposal is withdrawn. Topics are `admin`,
+/// `cancel`, plus the current admin address; data is `[new_admin]`.
+#[contractevent(topics = ["admin", "cancel"], data_format = "vec")]
+pub struct AdminProposalCancelled {
+    #[topic]
+    current_admin: Address,
+    new_admin: Address,
+}
+
+/// Emitted when the proposed admin accepts and control is transferred. Topics
+/// are `admin`, `transfer`, plus the previous admin address; data is `[new_admin]`.
 #[contractevent(topics = ["admin", "transfer"], data_format = "vec")]
 pub struct AdminTransferred {
     #[topic]
@@ -49,6 +169,9 @@ impl AdminContract {
     /// Emergency withdraw function - callable only by Admin
     /// Allows rescuing funds in case of critical bugs, exploits, or migration needs
     ///
+    /// Outside a declared emergency only the surplus above the token's
+    /// liabilities can be withdrawn, so escrow owed to users stays put.
+    ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `token` - The token address to withdraw
@@ -56,26 +179,138 @@ impl AdminContract {
     /// * `to` - The safe address to send funds to
     ///
     /// # Panics
-    /// Panics if called by non-admin address
+    /// Panics if called by non-admin address, with
+    /// `Error::InsufficientSurplus` if `amount` exceeds the surplus, or with
+    /// `Error::MultisigRequired` / `Error::TimelockRequired` once a multisig
+    /// or timelock guards this action
     pub fn admin_withdraw(env: Env, token: Address, amount: i128, to: Address) {
         // Check admin authentication
         check_admin(&env);
+        ensure_single_key_allowed(&env);
+        ensure_not_timelocked(&env);
 
-        // Initialize Token Client
-        let token_client = token::Client::new(&env, &token);
+        Self::execute_withdraw(&env, token, amount, to);
+    }
 
-        // Execute transfer: From contract -> to
-        token_client.transfer(&env.current_contract_address(), &to, &amount);
+    /// Declare or lift an emergency - callable only by Admin.
+    /// While active, `admin_withdraw` may move escrowed funds.
+    pub fn set_emergency(env: Env, active: bool) {
+        check_admin(&env);
 
-        // Emit EmergencyWithdraw event
-        EmergencyWithdraw {
-            token: token.clone(),
-            amount,
-            to: to.clone(),
+        env.storage().instance().set(&DataKey::Emergency, &active);
+
+        EmergencyStatusChanged { active }.publish(&env);
+    }
+
+    pub fn is_emergency(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Emergency)
+            .unwrap_or(false)
+    }
+
+    /// Grant `role` to `account` - callable only by Admin.
+    ///
+    /// # Panics
+    /// Panics with `Error::InvalidRole` for `Role::Admin`, which only moves
+    /// through `propose_admin`/`accept_admin`
+    pub fn grant_role(env: Env, role: Role, account: Address) {
+        check_admin(&env);
+
+        if role == Role::Admin {
+            panic_with_error!(&env, Error::InvalidRole);
+        }
+
+        let mut members = get_role_members(&env, role);
+        if !members.contains(&account) {
+            members.push_back(account.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::RoleMembers(role), &members);
+        }
+
+        RoleGranted { role, account }.publish(&env);
+    }
+
+    /// Revoke `role` from `account` - callable only by Admin.
+    pub fn revoke_role(env: Env, role: Role, account: Address) {
+        check_admin(&env);
+
+        let mut members = get_role_members(&env, role);
+        if let Some(index) = members.first_index_of(&account) {
+            members.remove(index);
+            env.storage()
+                .persistent()
+                .set(&DataKey::RoleMembers(role), &members);
+        }
+
+        RoleRevoked { role, account }.publish(&env);
+    }
+
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        has_role(&env, role, &account)
+    }
+
+    /// Accounts explicitly granted `role`. The admin holds every role
+    /// implicitly and is not listed.
+    pub fn get_role_members(env: Env, role: Role) -> Vec<Address> {
+        get_role_members(&env, role)
+    }
+
+    /// Set the global pause level - callable by a Pauser.
+    ///
+    /// `WithdrawOnly` halts everything except prize claims and refunds;
+    /// `Full` halts those too.
+    pub fn set_pause_mode(env: Env, caller: Address, mode: PauseMode) {
+        check_role(&env, &caller, Role::Pauser);
+
+        env.storage().instance().set(&DataKey::PauseMode, &mode);
+
+        PauseModeChanged { caller, mode }.publish(&env);
+    }
+
+    pub fn get_pause_mode(env: Env) -> PauseMode {
+        pause::get_pause_mode(&env)
+    }
+
+    /// Trip or reset the circuit breaker of a single module - callable by a
+    /// Pauser. Applies regardless of the global pause level.
+    pub fn set_module_paused(env: Env, caller: Address, module: PauseModule, paused: bool) {
+        check_role(&env, &caller, Role::Pauser);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ModulePaused(module), &paused);
+
+        ModulePauseChanged {
+            caller,
+            module,
+            paused,
         }
         .publish(&env);
     }
 
+    /// Whether entrypoints in `module` are currently rejected with
+    /// `Error::Paused`, by either the module flag or the global level.
+    pub fn is_paused(env: Env, module: PauseModule) -> bool {
+        pause::is_paused(&env, module)
+    }
+
+    /// Compare the contract's `token` balance with its liabilities in that
+    /// token, for monitoring.
+    pub fn get_solvency(env: Env, token: Address) -> Solvency {
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        let liabilities = get_liabilities(&env, &token);
+        let surplus = balance
+            .checked_sub(liabilities)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
+        Solvency {
+            balance,
+            liabilities,
+            surplus,
+        }
+    }
+
     /// Add a token to the whitelist - callable only by Admin
     /// Allows specific tokens to be used for giveaway creation
     ///
@@ -84,17 +319,14 @@ impl AdminContract {
     /// * `token` - The token address to whitelist
     ///
     /// # Panics
-    /// Panics if called by non-admin address
+    /// Panics if called by non-admin address, or with
+    /// `Error::MultisigRequired` once a multisig guards this action
     pub fn add_token(env: Env, token: Address) {
         // Check admin authentication
         check_admin(&env);
+        ensure_single_key_allowed(&env);
 
-        // Add token to whitelist
-        let token_key = DataKey::AllowedToken(token.clone());
-        env.storage().instance().set(&token_key, &true);
-
-        // Emit TokenAdded event
-        TokenAdded { token }.publish(&env);
+        Self::execute_add_token(&env, token);
     }
 
     /// Remove a token from the whitelist - callable only by Admin.
@@ -112,41 +344,165 @@ impl AdminContract {
     /// * `token` - The token address to delist
     ///
     /// # Panics
-    /// Panics if called by non-admin address
+    /// Panics if called by non-admin address, or with
+    /// `Error::TimelockRequired` once a timelock delay is set
     pub fn remove_token(env: Env, token: Address) {
         check_admin(&env);
+        ensure_not_timelocked(&env);
 
-        let token_key = DataKey::AllowedToken(token.clone());
-        env.storage().instance().set(&token_key, &false);
+        Self::execute_remove_token(&env, token);
+    }
 
-        TokenRemoved { token }.publish(&env);
+    /// Every token currently accepted by `create_giveaway`.
+    pub fn list_allowed_tokens(env: Env) -> Vec<Address> {
+        allowed_tokens(&env)
     }
 
-    pub fn toggle_request_verification(env: Env, request_id: u64) {
+    /// Change the base fee - callable only by Admin.
+    ///
+    /// Lowering the fee applies immediately. Raising it must go through the
+    /// timelock queue once a delay is set, so users get advance warning.
+    ///
+    /// # Panics
+    /// Panics with `Error::InvalidFeeConfig` above 10,000 bps, or with
+    /// `Error::TimelockRequired` for an increase while a delay is set
+    pub fn set_fee(env: Env, fee_bps: u32) {
         check_admin(&env);
 
-        let request_key = DataKey::HelpRequest(request_id);
-        let mut request: HelpRequest = env
+        let current: u32 = env
             .storage()
-            .persistent()
-            .get(&request_key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::HelpRequestNotFound));
+            .instance()
+            .get(&DataKey::Fee)
+            .unwrap_or(DEFAULT_FEE_BPS);
+        if fee_bps > current && timelock_delay(&env) > 0 {
+            panic_with_error!(&env, Error::TimelockRequired);
+        }
 
-        request.is_verified = !request.is_verified;
+        Self::execute_set_fee(&env, fee_bps);
+    }
 
-        env.storage().persistent().set(&request_key, &request);
-        RequestVerificationChanged {
-            request_id,
-            is_verified: request.is_verified,
+    /// Replace the reputation-based fee schedule - callable only by Admin.
+    ///
+    /// Tiers must be strictly ascending by `min_reputation`. A giveaway creator
+    /// pays the rate of the highest tier their reputation reaches; creators
+    /// below the first tier pay the base fee. An empty schedule disables tiers.
+    /// A schedule that charges any reputation more than the current one must
+    /// be queued as `TimelockAction::SetFeeSchedule` while a delay is set.
+    ///
+    /// # Panics
+    /// Panics with `Error::InvalidFeeConfig` if the tiers are out of order or
+    /// a rate exceeds 10,000 bps, or with `Error::TimelockRequired` for an
+    /// increase while a delay is set
+    pub fn set_fee_schedule(env: Env, tiers: Vec<FeeTier>) {
+        check_admin(&env);
+
+        if timelock_delay(&env) > 0 && Self::raises_schedule(&env, &tiers) {
+            panic_with_error!(&env, Error::TimelockRequired);
         }
-        .publish(&env);
+
+        Self::execute_set_fee_schedule(&env, tiers);
     }
 
-    /// Resolve an appeal for suspended content - callable only by Admin
-    /// Allows admin to restore the content or keep it suspended
-    pub fn resolve_appeal(env: Env, target_id: u64, restore: bool) {
+    pub fn get_fee_schedule(env: Env) -> Vec<FeeTier> {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeSchedule)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Pin the fee rate for a specific creator - callable only by Admin.
+    /// Takes precedence over the reputation schedule, e.g. 0 bps for
+    /// registered nonprofits. A rate above what the creator pays now must be
+    /// queued as `TimelockAction::SetFeeOverride` while a delay is set.
+    ///
+    /// # Panics
+    /// Panics with `Error::InvalidFeeConfig` above 10,000 bps, or with
+    /// `Error::TimelockRequired` for an increase while a delay is set
+    pub fn set_fee_override(env: Env, creator: Address, fee_bps: u32) {
         check_admin(&env);
 
+        if timelock_delay(&env) > 0 && fee_bps > Self::current_fee_bps(&env, &creator) {
+            panic_with_error!(&env, Error::TimelockRequired);
+        }
+
+        Self::execute_set_fee_override(&env, creator, fee_bps);
+    }
+
+    /// Remove a creator's fee override - callable only by Admin. If the
+    /// creator would then pay more, it must be queued as
+    /// `TimelockAction::RemoveFeeOverride` while a delay is set.
+    ///
+    /// # Panics
+    /// Panics with `Error::TimelockRequired` for an increase while a delay is
+    /// set
+    pub fn remove_fee_override(env: Env, creator: Address) {
+        check_admin(&env);
+
+        if timelock_delay(&env) > 0
+            && Self::scheduled_fee_bps_of(&env, &creator) > Self::current_fee_bps(&env, &creator)
+        {
+            panic_with_error!(&env, Error::TimelockRequired);
+        }
+
+        Self::execute_remove_fee_override(&env, creator);
+    }
+
+    pub fn get_fee_override(env: Env, creator: Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeOverride(creator))
+    }
+
+    /// Set how storage TTLs are extended - callable only by Admin.
+    ///
+    /// Entries touched by the contract, or by `bump_giveaway`/`bump_request`,
+    /// are extended to `extend_to` ledgers once fewer than `threshold` remain.
+    ///
+    /// # Panics
+    /// Panics with `Error::InvalidTtlConfig` unless
+    /// `0 < threshold < extend_to <= max_ttl`
+    pub fn set_ttl_config(env: Env, threshold: u32, extend_to: u32) {
+        check_admin(&env);
+
+        if threshold == 0 || threshold >= extend_to || extend_to > env.storage().max_ttl() {
+            panic_with_error!(&env, Error::InvalidTtlConfig);
+        }
+
+        let config = TtlConfig {
+            threshold,
+            extend_to,
+        };
+        env.storage().instance().set(&DataKey::TtlConfig, &config);
+        extend_instance(&env);
+    }
+
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        ttl_config(&env)
+    }
+
+    /// Flip a help request's verified badge - callable by a Moderator.
+    pub fn toggle_request_verification(env: Env, caller: Address, request_id: u64) {
+        check_role(&env, &caller, Role::Moderator);
+
+        let request = Self::load_request(&env, request_id);
+        let is_verified = !request.is_verified;
+        Self::set_request_verified(&env, request, is_verified);
+    }
+
+    /// Mark a help request as verified - callable by a Verifier. Unlike
+    /// `toggle_request_verification`, this can never remove the badge.
+    pub fn verify_help_request(env: Env, caller: Address, request_id: u64) {
+        check_role(&env, &caller, Role::Verifier);
+
+        let request = Self::load_request(&env, request_id);
+        Self::set_request_verified(&env, request, true);
+    }
+
+    /// Resolve an appeal for suspended content - callable by a Moderator
+    /// Allows the moderator to restore the content or keep it suspended
+    pub fn resolve_appeal(env: Env, caller: Address, target_id: u64, restore: bool) {
+        check_role(&env, &caller, Role::Moderator);
+
         let giveaway_key = DataKey::Giveaway(target_id);
         let request_key = DataKey::HelpRequest(target_id);
 
@@ -165,6 +521,7 @@ impl AdminContract {
                     GiveawayStatus::Suspended
                 };
                 env.storage().persistent().set(&giveaway_key, &giveaway);
+                extend_content_ttl(&env, &giveaway_key);
                 resolved = true;
             }
         }
@@ -183,6 +540,7 @@ impl AdminContract {
                         HelpRequestStatus::Suspended
                     };
                     env.storage().persistent().set(&request_key, &request);
+                    extend_content_ttl(&env, &request_key);
                     resolved = true;
                 }
             }
@@ -197,28 +555,335 @@ impl AdminContract {
         }
     }
 
-    /// Transfer admin role to a new address - callable only by the current Admin
+    /// Decide an appealed disqualification - callable by a Moderator while
+    /// the giveaway is `Active`. With `reinstate` the entrant is drawable
+    /// again; otherwise the disqualification stands and cannot be appealed
+    /// again.
+    pub fn resolve_disqualification_appeal(
+        env: Env,
+        caller: Address,
+        giveaway_id: u64,
+        participant: Address,
+        reinstate: bool,
+    ) {
+        check_role(&env, &caller, Role::Moderator);
+
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        if giveaway.status != GiveawayStatus::Active {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+        let record: Disqualification = env
+            .storage()
+            .persistent()
+            .get(&EntryKey::Disqualification(
+                giveaway_id,
+                participant.clone(),
+            ))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidStatus));
+        if !record.appealed {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+
+        if reinstate {
+            remove_disqualification(&env, giveaway_id, &participant);
+        }
+
+        DisqualificationAppealResolved {
+            giveaway_id,
+            participant,
+            reinstated: reinstate,
+        }
+        .publish(&env);
+    }
+
+    /// Propose a new admin - callable only by the current Admin.
+    ///
+    /// Nothing changes until `new_admin` signs `accept_admin`, so a mistyped
+    /// address cannot lock the contract. A new proposal replaces any pending
+    /// one. The proposal lapses after 7 days.
     ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `current_admin` - The address of the current admin (must match stored admin)
-    /// * `new_admin` - The address that will become the new admin
+    /// * `new_admin` - The address proposed as the next admin
     ///
     /// # Panics
-    /// Panics if called by a non-admin address or if `current_admin` does not match storage
-    pub fn transfer_admin(env: Env, current_admin: Address, new_admin: Address) {
+    /// Panics if called by a non-admin address or if `current_admin` does not match storage,
+    /// or with `Error::MultisigRequired` / `Error::TimelockRequired` once a multisig or
+    /// timelock guards this action
+    pub fn propose_admin(env: Env, current_admin: Address, new_admin: Address) {
         let admin = check_admin(&env);
 
         if admin != current_admin {
             panic_with_error!(&env, Error::NotAdmin);
         }
+        ensure_single_key_allowed(&env);
+        ensure_not_timelocked(&env);
+
+        Self::execute_propose_admin(&env, new_admin);
+    }
+
+    /// Accept a pending admin proposal - callable only by the proposed admin.
+    ///
+    /// # Panics
+    /// * `Error::NoPendingAdmin` if there is no proposal
+    /// * `Error::NotAdmin` if `new_admin` is not the proposed address
+    /// * `Error::ProposalExpired` if the proposal has lapsed
+    pub fn accept_admin(env: Env, new_admin: Address) {
+        new_admin.require_auth();
+
+        let pending: PendingAdmin = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdmin));
+
+        if pending.new_admin != new_admin {
+            panic_with_error!(&env, Error::NotAdmin);
+        }
+        if env.ledger().timestamp() > pending.expires_at {
+            panic_with_error!(&env, Error::ProposalExpired);
+        }
+
+        let previous_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotAdmin));
 
         env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
 
         AdminTransferred {
-            previous_admin: current_admin,
+            previous_admin,
             new_admin,
         }
         .publish(&env);
     }
+
+    /// Withdraw the pending admin proposal - callable only by the current Admin.
+    ///
+    /// # Panics
+    /// Panics with `Error::NoPendingAdmin` if there is nothing to cancel
+    pub fn cancel_admin_proposal(env: Env) {
+        let current_admin = check_admin(&env);
+
+        let pending: PendingAdmin = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdmin));
+
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminProposalCancelled {
+            current_admin,
+            new_admin: pending.new_admin,
+        }
+        .publish(&env);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdmin> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+}
+
+impl AdminContract {
+    /// Transfer `amount` of `token` out of the contract, limited to the
+    /// surplus unless an emergency is declared. Authorization is the caller's
+    /// responsibility: `admin_withdraw` or an executed multisig proposal.
+    pub(crate) fn execute_withdraw(env: &Env, token: Address, amount: i128, to: Address) {
+        // Initialize Token Client
+        let token_client = token::Client::new(env, &token);
+
+        let emergency: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Emergency)
+            .unwrap_or(false);
+        if !emergency {
+            let solvency = Self::get_solvency(env.clone(), token.clone());
+            if amount > solvency.surplus {
+                panic_with_error!(env, Error::InsufficientSurplus);
+            }
+        }
+
+        // Execute transfer: From contract -> to
+        token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+        // Emit EmergencyWithdraw event
+        EmergencyWithdraw {
+            token: token.clone(),
+            amount,
+            to,
+        }
+        .publish(env);
+    }
+
+    pub(crate) fn execute_add_token(env: &Env, token: Address) {
+        // Add token to whitelist
+        let token_key = DataKey::AllowedToken(token.clone());
+        env.storage().persistent().set(&token_key, &true);
+        extend_persistent(env, &token_key);
+
+        let mut tokens = allowed_tokens(env);
+        if !tokens.contains(&token) {
+            tokens.push_back(token.clone());
+            store_allowed_tokens(env, &tokens);
+        }
+
+        // Emit TokenAdded event
+        TokenAdded { token }.publish(env);
+    }
+
+    pub(crate) fn execute_remove_token(env: &Env, token: Address) {
+        let token_key = DataKey::AllowedToken(token.clone());
+        env.storage().persistent().remove(&token_key);
+
+        let mut tokens = allowed_tokens(env);
+        if let Some(index) = tokens.first_index_of(&token) {
+            tokens.remove(index);
+            store_allowed_tokens(env, &tokens);
+        }
+
+        TokenRemoved { token }.publish(env);
+    }
+
+    pub(crate) fn execute_set_fee_schedule(env: &Env, tiers: Vec<FeeTier>) {
+        let mut previous: Option<u64> = None;
+        for tier in tiers.iter() {
+            if tier.fee_bps > MAX_FEE_BPS {
+                panic_with_error!(env, Error::InvalidFeeConfig);
+            }
+            if let Some(previous) = previous {
+                if tier.min_reputation <= previous {
+                    panic_with_error!(env, Error::InvalidFeeConfig);
+                }
+            }
+            previous = Some(tier.min_reputation);
+        }
+
+        env.storage().instance().set(&DataKey::FeeSchedule, &tiers);
+
+        FeeScheduleUpdated { tiers }.publish(env);
+    }
+
+    pub(crate) fn execute_set_fee_override(env: &Env, creator: Address, fee_bps: u32) {
+        if fee_bps > MAX_FEE_BPS {
+            panic_with_error!(env, Error::InvalidFeeConfig);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeeOverride(creator.clone()), &fee_bps);
+
+        FeeOverrideSet { creator, fee_bps }.publish(env);
+    }
+
+    pub(crate) fn execute_remove_fee_override(env: &Env, creator: Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FeeOverride(creator.clone()));
+
+        FeeOverrideRemoved { creator }.publish(env);
+    }
+
+    fn base_fee_bps(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Fee)
+            .unwrap_or(DEFAULT_FEE_BPS)
+    }
+
+    /// Whether `tiers` would charge some reputation more than the stored
+    /// schedule. Both rates only change at a tier boundary, so comparing
+    /// them at every boundary of either schedule, and at zero, is enough.
+    fn raises_schedule(env: &Env, tiers: &Vec<FeeTier>) -> bool {
+        let base_fee_bps = Self::base_fee_bps(env);
+        let current: Vec<FeeTier> = Self::get_fee_schedule(env.clone());
+        let mut boundaries = Vec::from_array(env, [0u64]);
+        for tier in current.iter().chain(tiers.iter()) {
+            boundaries.push_back(tier.min_reputation);
+        }
+        boundaries.iter().any(|reputation| {
+            scheduled_fee_bps(tiers, base_fee_bps, reputation)
+                > scheduled_fee_bps(&current, base_fee_bps, reputation)
+        })
+    }
+
+    /// The rate `creator` would pay without an override, at their current
+    /// reputation.
+    fn scheduled_fee_bps_of(env: &Env, creator: &Address) -> u32 {
+        let reputation = ProfileContract::get_reputation(env.clone(), creator.clone());
+        scheduled_fee_bps(
+            &Self::get_fee_schedule(env.clone()),
+            Self::base_fee_bps(env),
+            reputation,
+        )
+    }
+
+    /// The rate `creator` pays now: their override, or the scheduled rate.
+    fn current_fee_bps(env: &Env, creator: &Address) -> u32 {
+        Self::get_fee_override(env.clone(), creator.clone())
+            .unwrap_or_else(|| Self::scheduled_fee_bps_of(env, creator))
+    }
+
+    pub(crate) fn execute_set_fee(env: &Env, fee_bps: u32) {
+        if fee_bps > MAX_FEE_BPS {
+            panic_with_error!(env, Error::InvalidFeeConfig);
+        }
+
+        env.storage().instance().set(&DataKey::Fee, &fee_bps);
+
+        FeeUpdated { fee_bps }.publish(env);
+    }
+
+    pub(crate) fn execute_propose_admin(env: &Env, new_admin: Address) {
+        let current_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::NotAdmin));
+        let expires_at = env.ledger().timestamp() + ADMIN_PROPOSAL_TTL_SECONDS;
+        env.storage().instance().set(
+            &DataKey::PendingAdmin,
+            &PendingAdmin {
+                new_admin: new_admin.clone(),
+                expires_at,
+            },
+        );
+
+        AdminProposed {
+            current_admin,
+            new_admin,
+            expires_at,
+        }
+        .publish(env);
+    }
+
+    fn load_request(env: &Env, request_id: u64) -> HelpRequest {
+        env.storage()
+            .persistent()
+            .get(&DataKey::HelpRequest(request_id))
+            .unwrap_or_else(|| panic_with_error!(env, Error::HelpRequestNotFound))
+    }
+
+    fn set_request_verified(env: &Env, mut request: HelpRequest, is_verified: bool) {
+        request.is_verified = is_verified;
+        env.storage()
+            .persistent()
+            .set(&DataKey::HelpRequest(request.id), &request);
+        extend_content_ttl(env, &DataKey::HelpRequest(request.id));
+
+        RequestVerificationChanged {
+            request_id: request.id,
+            is_verified,
+        }
+        .publish(env);
+    }
 }
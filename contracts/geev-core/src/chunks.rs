@@ -0,0 +1,148 @@
+use crate::ttl::extend_persistent;
+use soroban_sdk::{Address, Env, IntoVal, Val, Vec};
+
+/// Addresses stored per chunk entry of a `ChunkedList`.
+pub const LIST_CHUNK_SIZE: u32 = 64;
+
+/// A list of addresses spread over persistent entries of `LIST_CHUNK_SIZE`
+/// each, so no single entry grows with the list: chunk `n` is stored under
+/// `chunk_key(n)` and the length under `len_key`.
+pub struct ChunkedList<L, F> {
+    len_key: L,
+    chunk_key: F,
+}
+
+impl<L, K, F> ChunkedList<L, F>
+where
+    L: IntoVal<Env, Val>,
+    K: IntoVal<Env, Val>,
+    F: Fn(u32) -> K,
+{
+    pub fn new(len_key: L, chunk_key: F) -> Self {
+        Self { len_key, chunk_key }
+    }
+
+    pub fn len(&self, env: &Env) -> u32 {
+        env.storage().persistent().get(&self.len_key).unwrap_or(0)
+    }
+
+    pub fn chunk_count(&self, env: &Env) -> u32 {
+        self.len(env).div_ceil(LIST_CHUNK_SIZE)
+    }
+
+    pub fn chunk(&self, env: &Env, chunk: u32) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&(self.chunk_key)(chunk))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Append `address` and return the index it was stored at.
+    pub fn push(&self, env: &Env, address: &Address) -> u32 {
+        let index = self.len(env);
+        let mut chunk = self.chunk(env, index / LIST_CHUNK_SIZE);
+        chunk.push_back(address.clone());
+        self.store_chunk(env, index / LIST_CHUNK_SIZE, &chunk);
+        self.store_len(env, index + 1);
+        index
+    }
+
+    /// Remove the address at `index` by moving the last one into its place.
+    /// Returns the moved address, if another one took the slot.
+    pub fn swap_remove(&self, env: &Env, index: u32) -> Option<Address> {
+        let len = self.len(env);
+        if index >= len {
+            return None;
+        }
+        let last_chunk = (len - 1) / LIST_CHUNK_SIZE;
+        let mut tail = self.chunk(env, last_chunk);
+        let last = tail.pop_back()?;
+        self.store_chunk(env, last_chunk, &tail);
+        self.store_len(env, len - 1);
+
+        if index == len - 1 {
+            return None;
+        }
+        let mut chunk = self.chunk(env, index / LIST_CHUNK_SIZE);
+        chunk.set(index % LIST_CHUNK_SIZE, last.clone());
+        self.store_chunk(env, index / LIST_CHUNK_SIZE, &chunk);
+        Some(last)
+    }
+
+    /// Remove up to `limit` addresses from the end and return them, last
+    /// first. Each chunk touched is read and written once.
+    pub fn pop_up_to(&self, env: &Env, limit: u32) -> Vec<Address> {
+        let mut popped = Vec::new(env);
+        let mut len = self.len(env);
+        while len > 0 && popped.len() < limit {
+            let chunk_index = (len - 1) / LIST_CHUNK_SIZE;
+            let mut chunk = self.chunk(env, chunk_index);
+            while popped.len() < limit {
+                let Some(address) = chunk.pop_back() else {
+                    break;
+                };
+                popped.push_back(address);
+            }
+            len = chunk_index * LIST_CHUNK_SIZE + chunk.len();
+            self.store_chunk(env, chunk_index, &chunk);
+        }
+        self.store_len(env, len);
+        popped
+    }
+
+    /// Up to `limit` addresses starting at index `start`.
+    pub fn range(&self, env: &Env, start: u32, limit: u32) -> Vec<Address> {
+        let end = start.saturating_add(limit).min(self.len(env));
+        let mut addresses = Vec::new(env);
+        let mut index = start;
+        while index < end {
+            let chunk = self.chunk(env, index / LIST_CHUNK_SIZE);
+            let chunk_end = (end - (index - index % LIST_CHUNK_SIZE)).min(chunk.len());
+            addresses.append(&chunk.slice(index % LIST_CHUNK_SIZE..chunk_end));
+            index = index - index % LIST_CHUNK_SIZE + LIST_CHUNK_SIZE;
+        }
+        addresses
+    }
+
+    /// Extend the length entry and chunks `start_chunk` up to
+    /// `start_chunk + max_chunks`, calling `each` with every address in
+    /// them. Returns `true` if no chunk is left after these.
+    pub fn extend(
+        &self,
+        env: &Env,
+        start_chunk: u32,
+        max_chunks: u32,
+        mut each: impl FnMut(Address),
+    ) -> bool {
+        extend_persistent(env, &self.len_key);
+        let end_chunk = start_chunk
+            .saturating_add(max_chunks)
+            .min(self.chunk_count(env));
+        for chunk in start_chunk..end_chunk {
+            extend_persistent(env, &(self.chunk_key)(chunk));
+            for address in self.chunk(env, chunk).iter() {
+                each(address);
+            }
+        }
+        start_chunk.saturating_add(max_chunks) >= self.chunk_count(env)
+    }
+
+    fn store_chunk(&self, env: &Env, chunk: u32, addresses: &Vec<Address>) {
+        let chunk_key = (self.chunk_key)(chunk);
+        if addresses.is_empty() {
+            env.storage().persistent().remove(&chunk_key);
+        } else {
+            env.storage().persistent().set(&chunk_key, addresses);
+            extend_persistent(env, &chunk_key);
+        }
+    }
+
+    fn store_len(&self, env: &Env, len: u32) {
+        if len == 0 {
+            env.storage().persistent().remove(&self.len_key);
+        } else {
+            env.storage().persistent().set(&self.len_key, &len);
+            extend_persistent(env, &self.len_key);
+        }
+    }
+}
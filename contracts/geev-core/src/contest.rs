@@ -0,0 +1,76 @@
+use crate::ttl::extend_persistent;
+use crate::types::{AnswerPayload, EntryKey, RevealedAnswer};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+/// How long, in seconds, the creator has after `end_time` to reveal a skill
+/// contest's answer, and entrants then have to reveal theirs (1 day).
+pub const REVEAL_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
+/// The commitment to `answer` that `participant` submits: the sha256 of the
+/// XDR of its `AnswerPayload`. Binding the participant stops an entrant from
+/// copying another's commitment and then their reveal.
+pub fn answer_commitment(
+    env: &Env,
+    participant: &Address,
+    answer: &Bytes,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let payload = AnswerPayload {
+        participant: participant.clone(),
+        answer: answer.clone(),
+        salt: salt.clone(),
+    };
+    env.crypto().sha256(&payload.to_xdr(env)).into()
+}
+
+/// The correct answer of `giveaway_id`, if the creator has revealed it.
+pub fn revealed_answer(env: &Env, giveaway_id: u64) -> Option<RevealedAnswer> {
+    env.storage()
+        .persistent()
+        .get(&EntryKey::ContestAnswer(giveaway_id))
+}
+
+/// When revealing closes: `REVEAL_WINDOW_SECONDS` after the creator's
+/// reveal, or after `end_time` while the creator has not revealed.
+pub fn reveals_close_at(revealed: &Option<RevealedAnswer>, end_time: u64) -> u64 {
+    match revealed {
+        Some(revealed) => revealed.revealed_at + REVEAL_WINDOW_SECONDS,
+        None => end_time + REVEAL_WINDOW_SECONDS,
+    }
+}
+
+/// Entrants who revealed the correct answer, in the order they did.
+pub fn correct_revealers(env: &Env, giveaway_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&EntryKey::CorrectRevealers(giveaway_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_correct_revealer(env: &Env, giveaway_id: u64, participant: Address) {
+    let mut revealers = correct_revealers(env, giveaway_id);
+    revealers.push_back(participant);
+    let revealers_key = EntryKey::CorrectRevealers(giveaway_id);
+    env.storage().persistent().set(&revealers_key, &revealers);
+    extend_persistent(env, &revealers_key);
+}
+
+/// Extend the contest entries of a giveaway that are not per participant.
+pub fn extend_contest(env: &Env, giveaway_id: u64) {
+    extend_persistent(env, &EntryKey::Contest(giveaway_id));
+    extend_persistent(env, &EntryKey::ContestAnswer(giveaway_id));
+    extend_persistent(env, &EntryKey::CorrectRevealers(giveaway_id));
+}
+
+/// Delete the contest entries of a giveaway that are not per participant.
+pub fn remove_contest(env: &Env, giveaway_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&EntryKey::Contest(giveaway_id));
+    env.storage()
+        .persistent()
+        .remove(&EntryKey::ContestAnswer(giveaway_id));
+    env.storage()
+        .persistent()
+        .remove(&EntryKey::CorrectRevealers(giveaway_id));
+}
@@ -0,0 +1,341 @@
+use crate::chunks::ChunkedList;
+use crate::profile::ProfileContract;
+use crate::ttl::extend_persistent;
+use crate::types::{
+    DataKey, EligibilityRule, EntryCredentials, EntryError, EntryKey, PermitPayload,
+};
+use soroban_sdk::{token, xdr::ToXdr, Address, Bytes, BytesN, Env, TryFromVal, Val, Vec};
+
+/// Deepest nesting of `All`/`Any` a rule may use; a bare leaf is depth 1.
+pub const MAX_RULE_DEPTH: u32 = 4;
+
+/// Most rules, leaves and combinators together, in one giveaway's tree. Each
+/// leaf may cost a storage read or a token call on every entry.
+pub const MAX_RULE_NODES: u32 = 16;
+
+/// Longest Merkle proof accepted, enough for an allowlist of 2^32 addresses.
+pub const MAX_MERKLE_PROOF_LEN: u32 = 32;
+
+/// Furthest in the future, in seconds, a permit may expire (7 days). Spent
+/// nonces are kept in temporary storage for this long.
+pub const MAX_PERMIT_LIFETIME_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// `EntryKey::AdmittedBy` bits, one per allowlist, proof or permit rule
+/// that held for an entrant on entry.
+const ADMITTED_BY_ALLOWLIST: u32 = 1;
+const ADMITTED_BY_MERKLE_PROOF: u32 = 1 << 1;
+const ADMITTED_BY_PERMIT: u32 = 1 << 2;
+
+/// Shortest ledger close time assumed when sizing the TTL of spent nonces.
+/// Ledgers nominally close every 5 seconds; sizing for 1 keeps a nonce
+/// spent until its permit expires even if they close faster.
+const MIN_LEDGER_CLOSE_SECONDS: u64 = 1;
+
+/// Check that `rule` is within `MAX_RULE_DEPTH` and `MAX_RULE_NODES`, that
+/// every `All`/`Any` has at least one rule and that token amounts are
+/// positive.
+pub fn validate_rule(rule: &EligibilityRule) -> Result<(), EntryError> {
+    let mut nodes = 0;
+    validate_node(rule, 1, &mut nodes)
+}
+
+fn validate_node(rule: &EligibilityRule, depth: u32, nodes: &mut u32) -> Result<(), EntryError> {
+    *nodes += 1;
+    if depth > MAX_RULE_DEPTH || *nodes > MAX_RULE_NODES {
+        return Err(EntryError::InvalidEligibilityRule);
+    }
+    match rule {
+        EligibilityRule::MinTokenBalance(_, amount) if *amount <= 0 => {
+            Err(EntryError::InvalidEligibilityRule)
+        }
+        EligibilityRule::All(rules) | EligibilityRule::Any(rules) => {
+            if rules.is_empty() {
+                return Err(EntryError::InvalidEligibilityRule);
+            }
+            for child in rules.iter() {
+                validate_node(&child, depth + 1, nodes)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Evaluate `rule` for `participant` entering `giveaway_id`. A failing leaf
+/// reports its own error; `All` reports its first failing rule and `Any`,
+/// when nothing holds, the failure of its first rule.
+///
+/// With `credentials`, on entry, every allowlist, proof and permit rule that
+/// holds is recorded in `admitted`. Without, as when `hold_until_draw`
+/// re-checks a winner, those rules hold only if `admitted` records them:
+/// they were settled on entry, and the creator editing the list later does
+/// not undo an entry, but a rule that did not admit the entrant cannot
+/// stand in for one they no longer meet.
+pub fn check_rule(
+    env: &Env,
+    giveaway_id: u64,
+    rule: &EligibilityRule,
+    participant: &Address,
+    credentials: Option<&EntryCredentials>,
+    admitted: &mut u32,
+) -> Result<(), EntryError> {
+    match rule {
+        EligibilityRule::Allowlisted => check_credential(
+            credentials,
+            admitted,
+            ADMITTED_BY_ALLOWLIST,
+            EntryError::NotAllowlisted,
+            |_| {
+                if is_allowlisted(env, giveaway_id, participant) {
+                    Ok(())
+                } else {
+                    Err(EntryError::NotAllowlisted)
+                }
+            },
+        ),
+        EligibilityRule::MerkleAllowlisted => check_credential(
+            credentials,
+            admitted,
+            ADMITTED_BY_MERKLE_PROOF,
+            EntryError::InvalidMerkleProof,
+            |credentials| {
+                let root: Option<BytesN<32>> = env
+                    .storage()
+                    .persistent()
+                    .get(&EntryKey::AllowlistRoot(giveaway_id));
+                match root {
+                    Some(root)
+                        if verify_merkle_proof(
+                            env,
+                            &root,
+                            merkle_leaf(env, participant),
+                            &credentials.merkle_proof,
+                        ) =>
+                    {
+                        Ok(())
+                    }
+                    _ => Err(EntryError::InvalidMerkleProof),
+                }
+            },
+        ),
+        EligibilityRule::SignedPermit => check_credential(
+            credentials,
+            admitted,
+            ADMITTED_BY_PERMIT,
+            EntryError::InvalidPermit,
+            |credentials| spend_permit(env, giveaway_id, participant, credentials),
+        ),
+        EligibilityRule::MinReputation(min_reputation) => {
+            let reputation = ProfileContract::get_reputation(env.clone(), participant.clone());
+            if reputation >= *min_reputation {
+                Ok(())
+            } else {
+                Err(EntryError::InsufficientReputation)
+            }
+        }
+        EligibilityRule::HasProfile => {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Profile(participant.clone()))
+            {
+                Ok(())
+            } else {
+                Err(EntryError::ProfileRequired)
+            }
+        }
+        EligibilityRule::MinTokenBalance(token, min_balance) => {
+            if token::Client::new(env, token).balance(participant) >= *min_balance {
+                Ok(())
+            } else {
+                Err(EntryError::InsufficientTokenBalance)
+            }
+        }
+        EligibilityRule::All(rules) => {
+            for child in rules.iter() {
+                check_rule(env, giveaway_id, &child, participant, credentials, admitted)?;
+            }
+            Ok(())
+        }
+        EligibilityRule::Any(rules) => {
+            check_any(env, giveaway_id, rules, participant, credentials, admitted)
+        }
+    }
+}
+
+/// Settle an allowlist, proof or permit rule: with `credentials`, run
+/// `check` and record `bit` in `admitted` if it holds; without, it holds
+/// only if `bit` is recorded.
+fn check_credential(
+    credentials: Option<&EntryCredentials>,
+    admitted: &mut u32,
+    bit: u32,
+    unmet: EntryError,
+    check: impl FnOnce(&EntryCredentials) -> Result<(), EntryError>,
+) -> Result<(), EntryError> {
+    match credentials {
+        Some(credentials) => {
+            check(credentials)?;
+            *admitted |= bit;
+            Ok(())
+        }
+        None if *admitted & bit != 0 => Ok(()),
+        None => Err(unmet),
+    }
+}
+
+/// The members of a giveaway's `EligibilityRule::Allowlisted` list. Each
+/// member's `GiveawayAllowlist` entry holds its index here, except for
+/// members of v1 giveaways: their entries hold `true` and, having no list to
+/// be enumerated from, stay out of it.
+pub fn allowlist(giveaway_id: u64) -> ChunkedList<EntryKey, impl Fn(u32) -> EntryKey> {
+    ChunkedList::new(EntryKey::AllowlistCount(giveaway_id), move |chunk| {
+        EntryKey::AllowlistChunk(giveaway_id, chunk)
+    })
+}
+
+pub fn is_allowlisted(env: &Env, giveaway_id: u64, member: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::GiveawayAllowlist(giveaway_id, member.clone()))
+}
+
+/// Add `member` to the giveaway's allowlist. Returns `false` if it was
+/// already listed.
+pub fn add_allowlist_member(env: &Env, giveaway_id: u64, member: &Address) -> bool {
+    if is_allowlisted(env, giveaway_id, member) {
+        return false;
+    }
+    let index = allowlist(giveaway_id).push(env, member);
+    let allowed_key = DataKey::GiveawayAllowlist(giveaway_id, member.clone());
+    env.storage().persistent().set(&allowed_key, &index);
+    extend_persistent(env, &allowed_key);
+    true
+}
+
+/// Remove `member` from the giveaway's allowlist, moving the last member
+/// into its place. Returns `false` if it was not listed.
+pub fn remove_allowlist_member(env: &Env, giveaway_id: u64, member: &Address) -> bool {
+    let allowed_key = DataKey::GiveawayAllowlist(giveaway_id, member.clone());
+    let Some(entry) = env.storage().persistent().get::<_, Val>(&allowed_key) else {
+        return false;
+    };
+    env.storage().persistent().remove(&allowed_key);
+    // v1 members are flagged `true` and have no slot in the list.
+    if let Ok(index) = u32::try_from_val(env, &entry) {
+        if let Some(moved) = allowlist(giveaway_id).swap_remove(env, index) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::GiveawayAllowlist(giveaway_id, moved), &index);
+        }
+    }
+    true
+}
+
+fn check_any(
+    env: &Env,
+    giveaway_id: u64,
+    rules: &Vec<EligibilityRule>,
+    participant: &Address,
+    credentials: Option<&EntryCredentials>,
+    admitted: &mut u32,
+) -> Result<(), EntryError> {
+    let mut first_error = None;
+    for child in rules.iter() {
+        // Only the rule that admits the entrant is recorded, not the
+        // credentials of a branch that failed as a whole.
+        let before = *admitted;
+        match check_rule(env, giveaway_id, &child, participant, credentials, admitted) {
+            Ok(()) => return Ok(()),
+            Err(error) => {
+                *admitted = before;
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+    Err(first_error.unwrap_or(EntryError::InvalidEligibilityRule))
+}
+
+/// The allowlist leaf of `participant`: the SHA-256 of its XDR encoding.
+/// An encoded address is never 64 bytes long, so a leaf cannot pass for an
+/// inner node.
+pub fn merkle_leaf(env: &Env, participant: &Address) -> BytesN<32> {
+    env.crypto()
+        .sha256(&participant.clone().to_xdr(env))
+        .to_bytes()
+}
+
+/// Hash `leaf` up through `proof` and compare with `root`. Each pair is
+/// hashed smaller first, so proofs carry no left/right flags.
+pub fn verify_merkle_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    if proof.len() > MAX_MERKLE_PROOF_LEN {
+        return false;
+    }
+    let mut computed = leaf;
+    for sibling in proof.iter() {
+        let (first, second) = if computed <= sibling {
+            (computed, sibling)
+        } else {
+            (sibling, computed)
+        };
+        let mut pair = Bytes::from(first);
+        pair.append(&Bytes::from(second));
+        computed = env.crypto().sha256(&pair).to_bytes();
+    }
+    computed == *root
+}
+
+/// Check the permit in `credentials` against the giveaway's verifier key and
+/// mark its nonce spent. A signature that does not verify aborts the call
+/// with the host's crypto error rather than an `EntryError`.
+fn spend_permit(
+    env: &Env,
+    giveaway_id: u64,
+    participant: &Address,
+    credentials: &EntryCredentials,
+) -> Result<(), EntryError> {
+    let signature = credentials
+        .permit_signature
+        .as_ref()
+        .ok_or(EntryError::InvalidPermit)?;
+    let verifier: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&EntryKey::PermitVerifier(giveaway_id))
+        .ok_or(EntryError::InvalidPermit)?;
+
+    let now = env.ledger().timestamp();
+    if credentials.permit_expiry < now {
+        return Err(EntryError::PermitExpired);
+    }
+    if credentials.permit_expiry - now > MAX_PERMIT_LIFETIME_SECONDS {
+        return Err(EntryError::InvalidPermit);
+    }
+    let nonce_key = EntryKey::PermitNonce(giveaway_id, credentials.permit_nonce);
+    if env.storage().temporary().has(&nonce_key) {
+        return Err(EntryError::PermitNonceUsed);
+    }
+
+    let payload = PermitPayload {
+        contract: env.current_contract_address(),
+        giveaway_id,
+        participant: participant.clone(),
+        expiry: credentials.permit_expiry,
+        nonce: credentials.permit_nonce,
+    };
+    env.crypto()
+        .ed25519_verify(&verifier, &payload.to_xdr(env), signature);
+
+    let ledgers = ((credentials.permit_expiry - now) / MIN_LEDGER_CLOSE_SECONDS + 1)
+        .min(env.storage().max_ttl() as u64) as u32;
+    env.storage().temporary().set(&nonce_key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&nonce_key, ledgers, ledgers);
+    Ok(())
+}
@@ -1,11 +1,36 @@
 #![allow(clippy::too_many_arguments)]
+use crate::access::check_role;
+use crate::admin::scheduled_fee_bps;
+use crate::contest::{
+    add_correct_revealer, answer_commitment, correct_revealers, extend_contest, remove_contest,
+    revealed_answer, reveals_close_at,
+};
+use crate::eligibility::{
+    add_allowlist_member, allowlist, check_rule, is_allowlisted, remove_allowlist_member,
+    validate_rule,
+};
+use crate::escrow::{decrease_liabilities, increase_liabilities};
+use crate::multisig::ensure_single_key_allowed;
+use crate::participants::{
+    add_disqualification, add_participant, eligible_count, entrant_count, extend_participants,
+    has_entered, is_disqualified, participant_at, participant_count, rejoin_participant,
+    remove_disqualifications, remove_participants, withdraw_participant, EntrantEntries,
+};
+use crate::pause::ensure_not_paused;
 use crate::profile::ProfileContract;
+use crate::tokens::is_token_allowed;
+use crate::ttl::{extend_content_ttl, extend_persistent, PRUNE_BATCH_SIZE};
 use crate::types::{
-    DataKey, Error, Giveaway, GiveawayStatus, ParticipantVerification, SelectionMethod,
+    ContestOrdering, DataKey, Disqualification, EntryCredentials, EntryError, EntryKey, Error,
+    FeeMode, FeeTier, Giveaway, GiveawaySchedule, GiveawayStatus, GiveawaySummary, MeritEntry,
+    ParticipantVerification, PauseModule, RevealedAnswer, Role, SelectionMethod, SelectionState,
+    SkillContest,
 };
+use crate::upgrade::CURRENT_SCHEMA_VERSION;
 use crate::utils::with_reentrancy_guard;
 use soroban_sdk::{
-    contract, contractevent, contractimpl, panic_with_error, token, Address, Env, String, Vec,
+    contract, contractevent, contractimpl, panic_with_error, token, Address, Bytes, BytesN, Env,
+    String, Vec,
 };
 
 /// Duration, in seconds, that winners have to claim their prize after a
@@ -13,6 +38,22 @@ use soroban_sdk::{
 /// any unclaimed shares. Currently fixed for all giveaways (7 days).
 const CLAIM_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
 
+/// Fee rate applied when `init` has not stored one (100 bps = 1%).
+pub(crate) const DEFAULT_FEE_BPS: u32 = 100;
+
+/// Winners drawn, or participants ranked, per `continue_selection` call.
+/// Each unit reads one or two ledger entries, which keeps a batch within the
+/// per-transaction read limits.
+pub(crate) const SELECTION_BATCH_SIZE: u32 = 50;
+
+/// Share of the prize set aside by `begin_selection` to pay the keepers who
+/// drive a chunked selection (10 bps = 0.1%).
+pub(crate) const KEEPER_REWARD_BPS: u32 = 10;
+
+/// Runners-up a merit leaderboard keeps beyond the `winner_count` best, to
+/// move up when a leader leaves or is disqualified.
+pub(crate) const MERIT_LEADERBOARD_BUFFER: u32 = 8;
+
 #[contract]
 pub struct GiveawayContract;
 
@@ -28,7 +69,8 @@ pub struct GiveawayCreated {
 
 /// Emitted when a winner is definitively selected (`pick_winner`). Topics are fixed
 /// `giveaway`, `winner`, plus the winner address; data is `[giveaway_id, prize_amount]`
-/// as a Vec for downstream indexing (e.g. FCM).
+/// as a Vec for downstream indexing (e.g. FCM). `prize_amount` is the exact net
+/// amount `claim_prize` will pay that winner.
 #[contractevent(topics = ["giveaway", "winner"], data_format = "vec")]
 pub struct GiveawayWinnerSelected {
     #[topic]
@@ -37,6 +79,106 @@ pub struct GiveawayWinnerSelected {
     prize_amount: i128,
 }
 
+#[contractevent]
+pub struct SelectionStarted {
+    #[topic]
+    giveaway_id: u64,
+    total: u32,
+}
+
+#[contractevent]
+pub struct AllowlistRootUpdated {
+    #[topic]
+    giveaway_id: u64,
+    root: BytesN<32>,
+}
+
+#[contractevent]
+pub struct AllowlistMemberAdded {
+    #[topic]
+    giveaway_id: u64,
+    #[topic]
+    member: Address,
+}
+
+#[contractevent]
+pub struct AllowlistMemberRemoved {
+    #[topic]
+    giveaway_id: u64,
+    #[topic]
+    member: Address,
+}
+
+#[contractevent]
+pub struct ParticipantDisqualified {
+    #[topic]
+    giveaway_id: u64,
+    #[topic]
+    participant: Address,
+    reason_code: u32,
+}
+
+#[contractevent]
+pub struct ParticipantLeft {
+    #[topic]
+    giveaway_id: u64,
+    #[topic]
+    participant: Address,
+}
+
+#[contractevent]
+pub struct WinnerDisqualified {
+    #[topic]
+    giveaway_id: u64,
+    participant: Address,
+}
+
+#[contractevent]
+pub struct PrizeWonOnEntry {
+    #[topic]
+    giveaway_id: u64,
+    #[topic]
+    winner: Address,
+}
+
+#[contractevent]
+pub struct ContestAnswerRevealed {
+    #[topic]
+    giveaway_id: u64,
+    answer: Bytes,
+}
+
+#[contractevent]
+pub struct AnswerRevealed {
+    #[topic]
+    giveaway_id: u64,
+    #[topic]
+    participant: Address,
+    correct: bool,
+}
+
+#[contractevent]
+pub struct ReserveNotMet {
+    #[topic]
+    giveaway_id: u64,
+    entrants: u32,
+    min_participants: u32,
+}
+
+#[contractevent]
+pub struct GiveawayPruned {
+    #[topic]
+    giveaway_id: u64,
+}
+
+#[contractevent]
+pub struct KeeperRewarded {
+    #[topic]
+    keeper: Address,
+    giveaway_id: u64,
+    amount: i128,
+}
+
 #[allow(clippy::too_many_arguments)]
 #[contractimpl]
 impl GiveawayContract {
@@ -61,9 +203,19 @@ impl GiveawayContract {
             winner_count,
             verification,
             SelectionMethod::Random,
+            FeeMode::WinnerPays,
         )
     }
 
+    /// Create a giveaway with an explicit selection method and fee mode.
+    ///
+    /// With `FeeMode::CreatorPays` the fee on the whole prize is pulled from the
+    /// creator here, in addition to `amount`. It is held with the prize and
+    /// credited to `CollectedFees` once winners are picked; a giveaway
+    /// cancelled or refunded instead returns it to the creator.
+    ///
+    /// A `verification` rule outside `MAX_RULE_DEPTH` or `MAX_RULE_NODES`, or
+    /// with an empty `All`/`Any`, fails with `EntryError::InvalidEligibilityRule`.
     #[allow(clippy::too_many_arguments)]
     pub fn create_giveaway_with_selection(
         env: Env,
@@ -75,82 +227,712 @@ impl GiveawayContract {
         winner_count: u32,
         verification: Option<ParticipantVerification>,
         selection_method: SelectionMethod,
+        fee_mode: FeeMode,
+    ) -> u64 {
+        Self::create_scheduled_giveaway(
+            env,
+            creator,
+            token,
+            amount,
+            title,
+            winner_count,
+            verification,
+            selection_method,
+            fee_mode,
+            GiveawaySchedule {
+                start_time: None,
+                duration_seconds,
+                max_participants: None,
+                min_participants: 0,
+            },
+        )
+    }
+
+    /// Create a giveaway whose entries open at a later `start_time`, or that
+    /// caps or needs a number of entrants. Entries close
+    /// `schedule.duration_seconds` after they open.
+    ///
+    /// # Panics
+    /// * `EntryError::InvalidSchedule` if `max_participants` is below
+    ///   `winner_count` or `min_participants`, if `SelectionMethod::InstantWin`
+    ///   has no `max_participants`, or if a `FirstCome` or `InstantWin`
+    ///   giveaway sets `min_participants`
+    /// * `EntryError::AnswerRequired` for `SelectionMethod::SkillContest`;
+    ///   use `create_skill_contest`
+    /// * the errors of `create_giveaway_with_selection`
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_scheduled_giveaway(
+        env: Env,
+        creator: Address,
+        token: Address,
+        amount: i128,
+        title: String,
+        winner_count: u32,
+        verification: Option<ParticipantVerification>,
+        selection_method: SelectionMethod,
+        fee_mode: FeeMode,
+        schedule: GiveawaySchedule,
+    ) -> u64 {
+        if selection_method == SelectionMethod::SkillContest {
+            panic_with_error!(&env, EntryError::AnswerRequired);
+        }
+        Self::open_giveaway(
+            env,
+            creator,
+            token,
+            amount,
+            title,
+            winner_count,
+            verification,
+            selection_method,
+            fee_mode,
+            schedule,
+        )
+    }
+
+    /// Create a `SelectionMethod::SkillContest` giveaway, committing to the
+    /// correct answer with `contest.answer_hash` (see `answer_commitment`).
+    /// Entrants commit to theirs with `enter_giveaway_with_credentials`;
+    /// after `end_time` the creator calls `reveal_contest_answer`, entrants
+    /// call `reveal_answer`, and `finalize_contest_winners` picks winners
+    /// among the correct answers.
+    ///
+    /// # Panics
+    /// The errors of `create_scheduled_giveaway`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_skill_contest(
+        env: Env,
+        creator: Address,
+        token: Address,
+        amount: i128,
+        title: String,
+        winner_count: u32,
+        verification: Option<ParticipantVerification>,
+        fee_mode: FeeMode,
+        schedule: GiveawaySchedule,
+        contest: SkillContest,
+    ) -> u64 {
+        let giveaway_id = Self::open_giveaway(
+            env.clone(),
+            creator,
+            token,
+            amount,
+            title,
+            winner_count,
+            verification,
+            SelectionMethod::SkillContest,
+            fee_mode,
+            schedule,
+        );
+
+        let contest_key = EntryKey::Contest(giveaway_id);
+        env.storage().persistent().set(&contest_key, &contest);
+        extend_persistent(&env, &contest_key);
+
+        giveaway_id
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn open_giveaway(
+        env: Env,
+        creator: Address,
+        token: Address,
+        amount: i128,
+        title: String,
+        winner_count: u32,
+        verification: Option<ParticipantVerification>,
+        selection_method: SelectionMethod,
+        fee_mode: FeeMode,
+        schedule: GiveawaySchedule,
     ) -> u64 {
         creator.require_auth();
+        ensure_not_paused(&env, PauseModule::GiveawayCreation);
 
         if winner_count == 0 {
             panic_with_error!(&env, Error::InvalidWinnerCount);
         }
+        if let Some(max_participants) = schedule.max_participants {
+            if max_participants < winner_count || max_participants < schedule.min_participants {
+                panic_with_error!(&env, EntryError::InvalidSchedule);
+            }
+        }
+        match selection_method {
+            SelectionMethod::FirstCome | SelectionMethod::InstantWin
+                if schedule.min_participants > 0 =>
+            {
+                panic_with_error!(&env, EntryError::InvalidSchedule)
+            }
+            SelectionMethod::InstantWin if schedule.max_participants.is_none() => {
+                panic_with_error!(&env, EntryError::InvalidSchedule)
+            }
+            _ => {}
+        }
+        if let Some(verification) = &verification {
+            if let Err(error) = validate_rule(&verification.rule) {
+                panic_with_error!(&env, error);
+            }
+        }
+
+        // Check if token is whitelisted
+        if !is_token_allowed(&env, &token) {
+            panic_with_error!(&env, Error::TokenNotSupported);
+        }
+
+        let fee_bps = Self::creator_fee_bps(&env, &creator);
+        let upfront_fee = match fee_mode {
+            FeeMode::CreatorPays => Self::fee_amount(&env, amount, fee_bps),
+            FeeMode::WinnerPays => 0,
+        };
+        let total_deposit = amount
+            .checked_add(upfront_fee)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&creator, env.current_contract_address(), &total_deposit);
+        increase_liabilities(&env, &token, total_deposit);
+
+        let giveaway_id = Self::generate_id(&env);
+        if upfront_fee > 0 {
+            let fee_key = EntryKey::PendingFee(giveaway_id);
+            env.storage().persistent().set(&fee_key, &upfront_fee);
+            extend_persistent(&env, &fee_key);
+        }
+        let start_time = schedule
+            .start_time
+            .unwrap_or(0)
+            .max(env.ledger().timestamp());
+        let end_time = start_time + schedule.duration_seconds;
+
+        let mut eligibility = Vec::new(&env);
+        if let Some(verification) = &verification {
+            eligibility.push_back(verification.rule.clone());
+        }
+        let hold_until_draw = verification.as_ref().is_some_and(|v| v.hold_until_draw);
+
+        let giveaway = Giveaway {
+            id: giveaway_id,
+            creator: creator.clone(),
+            token: token.clone(),
+            amount,
+            title,
+            start_time,
+            end_time,
+            max_participants: schedule.max_participants,
+            min_participants: schedule.min_participants,
+            status: GiveawayStatus::Active,
+            winner_count,
+            winners: Vec::new(&env),
+            eligibility,
+            hold_until_draw,
+            selection_method,
+            claim_deadline: 0,
+            claimed_count: 0,
+            fee_mode,
+            fee_bps,
+        };
+
+        if let Some(verification) = &verification {
+            for addr in verification.allowlist.iter() {
+                add_allowlist_member(&env, giveaway_id, &addr);
+            }
+
+            if let Some(root) = &verification.merkle_root {
+                let root_key = EntryKey::AllowlistRoot(giveaway_id);
+                env.storage().persistent().set(&root_key, root);
+                extend_persistent(&env, &root_key);
+            }
+            if let Some(verifier) = &verification.permit_verifier {
+                let verifier_key = EntryKey::PermitVerifier(giveaway_id);
+                env.storage().persistent().set(&verifier_key, verifier);
+                extend_persistent(&env, &verifier_key);
+            }
+        }
+
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        env.storage().persistent().set(&giveaway_key, &giveaway);
+        extend_content_ttl(&env, &giveaway_key);
+
+        GiveawayCreated {
+            giveaway_id,
+            creator,
+            token_address: token,
+            total_amount: amount,
+            end_time,
+        }
+        .publish(&env);
+
+        giveaway_id
+    }
+
+    pub fn enter_giveaway(env: Env, participant: Address, giveaway_id: u64) {
+        Self::enter_giveaway_with_credentials(
+            env.clone(),
+            participant,
+            giveaway_id,
+            EntryCredentials {
+                merkle_proof: Vec::new(&env),
+                permit_signature: None,
+                permit_expiry: 0,
+                permit_nonce: 0,
+                answer_commitment: None,
+            },
+        )
+    }
+
+    /// Enter a giveaway whose rules need proof, such as a Merkle allowlist
+    /// or a verifier's permit.
+    ///
+    /// # Panics
+    /// * `EntryError::InvalidMerkleProof` if a `MerkleAllowlisted` rule is
+    ///   not met by `credentials.merkle_proof`
+    /// * `EntryError::InvalidPermit`, `EntryError::PermitExpired` or
+    ///   `EntryError::PermitNonceUsed` if a `SignedPermit` rule is not met,
+    ///   and a host crypto error if the permit signature does not verify
+    /// * `EntryError::AnswerRequired` if a skill contest is entered without
+    ///   `credentials.answer_commitment`
+    /// * the errors of `enter_giveaway`
+    pub fn enter_giveaway_with_credentials(
+        env: Env,
+        participant: Address,
+        giveaway_id: u64,
+        credentials: EntryCredentials,
+    ) {
+        participant.require_auth();
+        ensure_not_paused(&env, PauseModule::Entries);
+
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
+
+        if giveaway.status != GiveawayStatus::Active {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+        if env.ledger().timestamp() < giveaway.start_time {
+            panic_with_error!(&env, EntryError::EntriesNotOpen);
+        }
+        if env.ledger().timestamp() > giveaway.end_time {
+            panic_with_error!(&env, Error::GiveawayEnded);
+        }
+
+        let has_entered_key = DataKey::HasEntered(giveaway_id, participant.clone());
+        let rejoining = match env.storage().persistent().get::<_, bool>(&has_entered_key) {
+            Some(true) => panic_with_error!(&env, Error::AlreadyEntered),
+            Some(false)
+                if matches!(
+                    giveaway.selection_method,
+                    SelectionMethod::FirstCome | SelectionMethod::InstantWin
+                ) =>
+            {
+                panic_with_error!(&env, EntryError::AlreadyLeft)
+            }
+            Some(false) => true,
+            None => false,
+        };
+
+        let entrants = entrant_count(&env, giveaway_id);
+        if let Some(max_participants) = giveaway.max_participants {
+            if entrants >= max_participants {
+                panic_with_error!(&env, EntryError::GiveawayFull);
+            }
+        }
+
+        let admitted_by = Self::verify_participant(&env, &giveaway, &participant, &credentials);
+        if giveaway.hold_until_draw {
+            let admitted_key = EntryKey::AdmittedBy(giveaway_id, participant.clone());
+            env.storage().persistent().set(&admitted_key, &admitted_by);
+            extend_persistent(&env, &admitted_key);
+        }
+        if giveaway.selection_method == SelectionMethod::SkillContest {
+            let Some(commitment) = credentials.answer_commitment else {
+                panic_with_error!(&env, EntryError::AnswerRequired);
+            };
+            let commitment_key = EntryKey::AnswerCommitment(giveaway_id, participant.clone());
+            env.storage().persistent().set(&commitment_key, &commitment);
+            extend_persistent(&env, &commitment_key);
+        }
+
+        // Only the participant list and its count change; the `Giveaway`
+        // entry itself is not rewritten.
+        let index_key = EntryKey::EntryIndex(giveaway_id, participant.clone());
+        let entry_index = if rejoining {
+            rejoin_participant(&env, giveaway_id, &participant);
+            // Merit entrants who joined before their index was stored rank
+            // like one arriving now.
+            env.storage()
+                .persistent()
+                .get(&index_key)
+                .unwrap_or_else(|| participant_count(&env, giveaway_id))
+        } else {
+            env.storage().persistent().set(&has_entered_key, &true);
+            extend_persistent(&env, &has_entered_key);
+            add_participant(&env, giveaway_id, &participant)
+        };
+
+        match giveaway.selection_method {
+            SelectionMethod::Merit => {
+                if !rejoining {
+                    env.storage().persistent().set(&index_key, &entry_index);
+                    extend_persistent(&env, &index_key);
+                }
+                Self::record_merit_entry(&env, &giveaway, &participant, entry_index);
+            }
+            SelectionMethod::FirstCome => {
+                Self::award_on_entry(&env, &giveaway_key, giveaway, participant);
+            }
+            SelectionMethod::InstantWin if Self::roll_instant_win(&env, &giveaway, entrants) => {
+                Self::award_on_entry(&env, &giveaway_key, giveaway, participant);
+            }
+            _ => {}
+        }
+    }
+
+    /// Withdraw `participant`'s entry while the giveaway is `Active` and
+    /// before `end_time`. Their slot stays in the entry order but is left
+    /// empty, so selection passes over it and it no longer counts towards
+    /// the entrants; once every entrant has left, the creator can cancel
+    /// again. Entering again before `end_time` takes the slot back, except in
+    /// `FirstCome` and `InstantWin` giveaways: there a prize won on entry is
+    /// given up, and coming back would be a fresh roll for one.
+    ///
+    /// # Panics
+    /// * `Error::InvalidStatus` if the giveaway is not `Active`
+    /// * `Error::GiveawayEnded` if `end_time` has passed
+    /// * `EntryError::NotEntered` if `participant` holds no entry
+    /// * `EntryError::ParticipantDisqualified` if they are disqualified
+    pub fn leave_giveaway(env: Env, participant: Address, giveaway_id: u64) {
+        participant.require_auth();
+        ensure_not_paused(&env, PauseModule::Entries);
+
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let mut giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
+
+        if giveaway.status != GiveawayStatus::Active {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+        if env.ledger().timestamp() > giveaway.end_time {
+            panic_with_error!(&env, Error::GiveawayEnded);
+        }
+        if !has_entered(&env, giveaway_id, &participant) {
+            panic_with_error!(&env, EntryError::NotEntered);
+        }
+        if is_disqualified(&env, giveaway_id, &participant) {
+            panic_with_error!(&env, EntryError::ParticipantDisqualified);
+        }
+
+        withdraw_participant(&env, giveaway_id, &participant);
+        Self::remove_from_leaderboard(&env, &giveaway, &participant);
+        Self::release_prize(&env, &giveaway_key, &mut giveaway, &participant);
+        for key in [
+            EntryKey::AnswerCommitment(giveaway_id, participant.clone()),
+            EntryKey::AdmittedBy(giveaway_id, participant.clone()),
+        ] {
+            env.storage().persistent().remove(&key);
+        }
+
+        ParticipantLeft {
+            giveaway_id,
+            participant,
+        }
+        .publish(&env);
+    }
+
+    /// Replace the Merkle root that `EligibilityRule::MerkleAllowlisted`
+    /// proofs are checked against - callable only by the creator, while the
+    /// giveaway is `Active`. Participants who already entered stay entered.
+    pub fn set_allowlist_root(env: Env, creator: Address, giveaway_id: u64, root: BytesN<32>) {
+        creator.require_auth();
+
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
+
+        if giveaway.creator != creator {
+            panic_with_error!(&env, Error::NotCreator);
+        }
+        if giveaway.status != GiveawayStatus::Active {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+
+        let root_key = EntryKey::AllowlistRoot(giveaway_id);
+        env.storage().persistent().set(&root_key, &root);
+        extend_persistent(&env, &root_key);
+
+        AllowlistRootUpdated { giveaway_id, root }.publish(&env);
+    }
+
+    /// Add `members` to the giveaway's allowlist - callable only by the
+    /// creator, while the giveaway is `Active` and before `end_time`.
+    /// Addresses already on the list are skipped.
+    pub fn add_to_allowlist(env: Env, creator: Address, giveaway_id: u64, members: Vec<Address>) {
+        Self::ensure_allowlist_editable(&env, &creator, giveaway_id);
+        for member in members.iter() {
+            if !add_allowlist_member(&env, giveaway_id, &member) {
+                continue;
+            }
+
+            AllowlistMemberAdded {
+                giveaway_id,
+                member,
+            }
+            .publish(&env);
+        }
+    }
+
+    /// Remove `members` from the giveaway's allowlist - callable only by the
+    /// creator, while the giveaway is `Active` and before `end_time`.
+    /// Addresses not on the list are skipped. A removed member who has
+    /// already entered stays entered.
+    pub fn remove_from_allowlist(
+        env: Env,
+        creator: Address,
+        giveaway_id: u64,
+        members: Vec<Address>,
+    ) {
+        Self::ensure_allowlist_editable(&env, &creator, giveaway_id);
+        for member in members.iter() {
+            if !remove_allowlist_member(&env, giveaway_id, &member) {
+                continue;
+            }
+
+            AllowlistMemberRemoved {
+                giveaway_id,
+                member,
+            }
+            .publish(&env);
+        }
+    }
+
+    pub fn is_allowlisted(env: Env, giveaway_id: u64, address: Address) -> bool {
+        is_allowlisted(&env, giveaway_id, &address)
+    }
+
+    /// Up to `limit` allowlisted addresses, starting at position `start`.
+    /// Members are listed in the order they were added, except that removing
+    /// one moves the last member into its place.
+    pub fn get_allowlist(env: Env, giveaway_id: u64, start: u32, limit: u32) -> Vec<Address> {
+        allowlist(giveaway_id).range(&env, start, limit)
+    }
+
+    /// Remove an entrant from the draw - callable by the creator or admin
+    /// while the giveaway is `Active`. The entry stays in place, so entry
+    /// order is unchanged, but `pick_winner`, merit selection and manual
+    /// winners all pass over it, and a prize it won on entry goes back into
+    /// play. The entrant can appeal through
+    /// `GovernanceContract::appeal_disqualification`.
+    ///
+    /// # Panics
+    /// * `Error::NotCreator` if `caller` is neither the creator nor the admin
+    /// * `Error::InvalidStatus` if the giveaway is not `Active`
+    /// * `Error::GiveawayEnded` once `end_time` is reached, so entrants
+    ///   cannot be struck off after the field is set, e.g. while skill
+    ///   contest answers are being revealed
+    /// * `EntryError::NotEntered` if `participant` has not entered
+    /// * `EntryError::AlreadyDisqualified` if they already are
+    pub fn disqualify_participant(
+        env: Env,
+        caller: Address,
+        giveaway_id: u64,
+        participant: Address,
+        reason_code: u32,
+    ) {
+        caller.require_auth();
+
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let mut giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
+
+        Self::ensure_creator_or_admin(&env, &caller, &giveaway);
+        if giveaway.status != GiveawayStatus::Active {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+        if env.ledger().timestamp() >= giveaway.end_time {
+            panic_with_error!(&env, Error::GiveawayEnded);
+        }
+        if !has_entered(&env, giveaway_id, &participant) {
+            panic_with_error!(&env, EntryError::NotEntered);
+        }
+        if is_disqualified(&env, giveaway_id, &participant) {
+            panic_with_error!(&env, EntryError::AlreadyDisqualified);
+        }
+
+        add_disqualification(&env, giveaway_id, &participant, reason_code);
+        Self::remove_from_leaderboard(&env, &giveaway, &participant);
+        Self::release_prize(&env, &giveaway_key, &mut giveaway, &participant);
+
+        ParticipantDisqualified {
+            giveaway_id,
+            participant,
+            reason_code,
+        }
+        .publish(&env);
+    }
+
+    pub fn get_disqualification(
+        env: Env,
+        giveaway_id: u64,
+        participant: Address,
+    ) -> Option<Disqualification> {
+        env.storage()
+            .persistent()
+            .get(&EntryKey::Disqualification(giveaway_id, participant))
+    }
+
+    /// Cancel an active giveaway that has no entrants, either because nobody
+    /// entered or because everyone who did has left, and return its entire
+    /// escrowed prize, and any fee paid upfront, to the creator.
+    ///
+    /// Cancellation is deliberately unavailable while anyone holds an entry,
+    /// disqualified entrants included, or after winner selection. This
+    /// prevents a creator from withdrawing a prize after participants have
+    /// begun relying on the campaign.
+    pub fn cancel_giveaway(env: Env, creator: Address, giveaway_id: u64) {
+        creator.require_auth();
+        ensure_not_paused(&env, PauseModule::Refunds);
 
-        // Check if token is whitelisted
-        let token_key = DataKey::AllowedToken(token.clone());
-        let is_allowed: bool = env.storage().instance().get(&token_key).unwrap_or(false);
+        with_reentrancy_guard(&env, || {
+            let giveaway_key = DataKey::Giveaway(giveaway_id);
+            let mut giveaway: Giveaway = env
+                .storage()
+                .persistent()
+                .get(&giveaway_key)
+                .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+            extend_content_ttl(&env, &giveaway_key);
 
-        if !is_allowed {
-            panic_with_error!(&env, Error::TokenNotSupported);
-        }
+            if giveaway.creator != creator {
+                panic_with_error!(&env, Error::NotCreator);
+            }
+            if giveaway.status != GiveawayStatus::Active || entrant_count(&env, giveaway_id) != 0 {
+                panic_with_error!(&env, Error::InvalidStatus);
+            }
 
-        let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&creator, env.current_contract_address(), &amount);
+            Self::refund_creator(&env, &giveaway_key, &mut giveaway);
+        })
+    }
 
-        let giveaway_id = Self::generate_id(&env);
-        let end_time = env.ledger().timestamp() + duration_seconds;
+    /// Return the prize of a giveaway whose `min_participants` reserve was
+    /// not met by `end_time` to its creator, cancelling it instead of a
+    /// draw. Callable by anyone; selection refuses such a giveaway with
+    /// `EntryError::ReserveNotMet` until this is called. A fee paid upfront
+    /// under `FeeMode::CreatorPays` is returned with the prize.
+    ///
+    /// # Panics
+    /// * `Error::InvalidStatus` if the giveaway is not `Active`, or if the
+    ///   reserve was met and it can be drawn
+    /// * `Error::GiveawayStillActive` until `end_time` has passed
+    pub fn settle_unmet_reserve(env: Env, giveaway_id: u64) {
+        ensure_not_paused(&env, PauseModule::Refunds);
 
-        let verification_type = match &verification {
-            Some(v) if v.uses_reputation => 2,
-            Some(_) => 1,
-            None => 0,
-        };
-        let min_reputation = verification.as_ref().map(|v| v.min_reputation).unwrap_or(0);
+        with_reentrancy_guard(&env, || {
+            let giveaway_key = DataKey::Giveaway(giveaway_id);
+            let mut giveaway: Giveaway = env
+                .storage()
+                .persistent()
+                .get(&giveaway_key)
+                .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+            extend_content_ttl(&env, &giveaway_key);
 
-        let giveaway = Giveaway {
-            id: giveaway_id,
-            creator: creator.clone(),
-            token: token.clone(),
-            amount,
-            title,
-            participant_count: 0,
-            end_time,
-            status: GiveawayStatus::Active,
-            winner_count,
-            winners: Vec::new(&env),
-            verification_type,
-            min_reputation,
-            selection_method,
-            claim_deadline: 0,
-            claimed_count: 0,
-        };
+            if giveaway.status != GiveawayStatus::Active {
+                panic_with_error!(&env, Error::InvalidStatus);
+            }
+            if env.ledger().timestamp() <= giveaway.end_time {
+                panic_with_error!(&env, Error::GiveawayStillActive);
+            }
+            let entrants = entrant_count(&env, giveaway_id);
+            if entrants >= giveaway.min_participants {
+                panic_with_error!(&env, Error::InvalidStatus);
+            }
 
-        if let Some(verification) = &verification {
-            if !verification.uses_reputation {
-                for addr in verification.allowlist.iter() {
-                    env.storage().persistent().set(
-                        &DataKey::GiveawayAllowlist(giveaway_id, addr.clone()),
-                        &true,
-                    );
-                }
+            Self::refund_creator(&env, &giveaway_key, &mut giveaway);
+
+            ReserveNotMet {
+                giveaway_id,
+                entrants,
+                min_participants: giveaway.min_participants,
             }
-        }
+            .publish(&env);
+        })
+    }
 
-        env.storage()
+    /// Extend the TTL of a giveaway and every entry belonging to it: the
+    /// participant list, allowlist and claim records. Callable by anyone, so
+    /// participants can keep a long-running giveaway from being archived.
+    ///
+    /// Participant and allowlist entries are extended `max_chunks` chunks
+    /// at a time, from chunk `start_chunk` of each list, so a large giveaway
+    /// takes several calls; the entries not kept in chunks are extended by
+    /// the call with `start_chunk` 0. Returns `true` once no chunk is left
+    /// after this call's.
+    pub fn bump_giveaway(env: Env, giveaway_id: u64, start_chunk: u32, max_chunks: u32) -> bool {
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let giveaway: Giveaway = env
+            .storage()
             .persistent()
-            .set(&DataKey::Giveaway(giveaway_id), &giveaway);
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
 
-        GiveawayCreated {
-            giveaway_id,
-            creator,
-            token_address: token,
-            total_amount: amount,
-            end_time,
+        if start_chunk == 0 {
+            if giveaway.selection_method == SelectionMethod::SkillContest {
+                extend_contest(&env, giveaway_id);
+            }
+            extend_persistent(&env, &EntryKey::AllowlistRoot(giveaway_id));
+            extend_persistent(&env, &EntryKey::PermitVerifier(giveaway_id));
+            extend_persistent(&env, &EntryKey::PendingFee(giveaway_id));
+            extend_persistent(&env, &DataKey::MeritLeaderboard(giveaway_id));
+            extend_persistent(&env, &DataKey::SelectionState(giveaway_id));
+
+            for winner in giveaway.winners.iter() {
+                extend_persistent(&env, &DataKey::Claimed(giveaway_id, winner));
+            }
         }
-        .publish(&env);
 
-        giveaway_id
+        let participants_done = extend_participants(
+            &env,
+            giveaway_id,
+            Self::entrant_entries(&giveaway),
+            start_chunk,
+            max_chunks,
+        );
+        let allowlist_done =
+            allowlist(giveaway_id).extend(&env, start_chunk, max_chunks, |member| {
+                extend_persistent(&env, &DataKey::GiveawayAllowlist(giveaway_id, member));
+            });
+        participants_done && allowlist_done
     }
 
-    pub fn enter_giveaway(env: Env, participant: Address, giveaway_id: u64) {
-        participant.require_auth();
-
+    /// Delete the per-participant entries of a `Completed` or `Cancelled`
+    /// giveaway: the participant list and `HasEntered` flags,
+    /// disqualifications, the allowlist, the merit leaderboard, skill-contest
+    /// answers and the `Claimed` records. Callable by anyone.
+    ///
+    /// Deletes at most `PRUNE_BATCH_SIZE` entries per call and returns `true`
+    /// once all are gone. The first call archives a `GiveawaySummary`; the
+    /// last deletes the `Giveaway` itself. In between, the stored giveaway's
+    /// `winners` shrinks as their `Claimed` records go, so read the summary.
+    ///
+    /// # Panics
+    /// * `Error::GiveawayNotFound` if the giveaway does not exist or has
+    ///   already been pruned
+    /// * `Error::InvalidStatus` unless the giveaway is `Completed` or
+    ///   `Cancelled`
+    pub fn prune_giveaway(env: Env, giveaway_id: u64) -> bool {
         let giveaway_key = DataKey::Giveaway(giveaway_id);
         let mut giveaway: Giveaway = env
             .storage()
@@ -158,37 +940,154 @@ impl GiveawayContract {
             .get(&giveaway_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
 
-        if giveaway.status != GiveawayStatus::Active {
+        if giveaway.status != GiveawayStatus::Completed
+            && giveaway.status != GiveawayStatus::Cancelled
+        {
             panic_with_error!(&env, Error::InvalidStatus);
         }
-        if env.ledger().timestamp() > giveaway.end_time {
-            panic_with_error!(&env, Error::GiveawayEnded);
+
+        let summary_key = DataKey::GiveawaySummary(giveaway_id);
+        if !env.storage().persistent().has(&summary_key) {
+            let summary = GiveawaySummary {
+                id: giveaway_id,
+                creator: giveaway.creator.clone(),
+                token: giveaway.token.clone(),
+                amount: giveaway.amount,
+                status: giveaway.status.clone(),
+                winners: giveaway.winners.clone(),
+                participant_count: entrant_count(&env, giveaway_id),
+                claimed_count: giveaway.claimed_count,
+                end_time: giveaway.end_time,
+                claim_deadline: giveaway.claim_deadline,
+                pruned_at: env.ledger().timestamp(),
+            };
+            env.storage().persistent().set(&summary_key, &summary);
+            extend_persistent(&env, &summary_key);
         }
 
-        let has_entered_key = DataKey::HasEntered(giveaway_id, participant.clone());
-        if env.storage().persistent().has(&has_entered_key) {
-            panic_with_error!(&env, Error::AlreadyEntered);
+        let mut budget = PRUNE_BATCH_SIZE;
+        budget -= remove_participants(&env, giveaway_id, budget, Self::entrant_entries(&giveaway));
+        if participant_count(&env, giveaway_id) > 0 {
+            return false;
         }
+        budget -= remove_disqualifications(&env, giveaway_id, budget);
 
-        Self::verify_participant(&env, &giveaway, &participant);
+        for member in allowlist(giveaway_id).pop_up_to(&env, budget).iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::GiveawayAllowlist(giveaway_id, member));
+            budget -= 1;
+        }
 
-        env.storage().persistent().set(&has_entered_key, &true);
+        // The summary holds the winners, so the stored list is the cursor.
+        while budget > 0 {
+            let Some(winner) = giveaway.winners.pop_back() else {
+                break;
+            };
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Claimed(giveaway_id, winner));
+            budget -= 1;
+        }
 
-        let index_key = DataKey::ParticipantIndex(giveaway_id, giveaway.participant_count);
-        env.storage().persistent().set(&index_key, &participant);
+        if budget == 0 {
+            env.storage().persistent().set(&giveaway_key, &giveaway);
+            return false;
+        }
 
-        giveaway.participant_count += 1;
-        env.storage().persistent().set(&giveaway_key, &giveaway);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::MeritLeaderboard(giveaway_id));
+        env.storage()
+            .persistent()
+            .remove(&EntryKey::AllowlistRoot(giveaway_id));
+        env.storage()
+            .persistent()
+            .remove(&EntryKey::PermitVerifier(giveaway_id));
+        if giveaway.selection_method == SelectionMethod::SkillContest {
+            remove_contest(&env, giveaway_id);
+        }
+        env.storage().persistent().remove(&giveaway_key);
+
+        GiveawayPruned { giveaway_id }.publish(&env);
+        true
+    }
+
+    /// The stored giveaway, until `prune_giveaway` replaces it with a
+    /// summary.
+    pub fn get_giveaway(env: Env, giveaway_id: u64) -> Option<Giveaway> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Giveaway(giveaway_id))
+    }
+
+    /// Entrants currently holding an entry, as counted against
+    /// `max_participants` and `min_participants`.
+    pub fn get_entrant_count(env: Env, giveaway_id: u64) -> u32 {
+        entrant_count(&env, giveaway_id)
+    }
+
+    pub fn get_giveaway_summary(env: Env, giveaway_id: u64) -> Option<GiveawaySummary> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GiveawaySummary(giveaway_id))
+    }
+
+    /// Check `participant`'s entry against the giveaway's rules and return
+    /// the credential rules that admitted them, for `EntryKey::AdmittedBy`.
+    fn verify_participant(
+        env: &Env,
+        giveaway: &Giveaway,
+        participant: &Address,
+        credentials: &EntryCredentials,
+    ) -> u32 {
+        let mut admitted_by = 0;
+        if let Err(error) = Self::check_eligibility(
+            env,
+            giveaway,
+            participant,
+            Some(credentials),
+            &mut admitted_by,
+        ) {
+            panic_with_error!(env, error);
+        }
+        admitted_by
+    }
+
+    fn check_eligibility(
+        env: &Env,
+        giveaway: &Giveaway,
+        participant: &Address,
+        credentials: Option<&EntryCredentials>,
+        admitted: &mut u32,
+    ) -> Result<(), EntryError> {
+        for rule in giveaway.eligibility.iter() {
+            check_rule(env, giveaway.id, &rule, participant, credentials, admitted)?;
+        }
+        Ok(())
     }
 
-    /// Cancel an active giveaway before anyone has entered and return its
-    /// entire escrowed prize to the creator.
+    /// The per-participant entries `giveaway` keeps besides `HasEntered`.
+    fn entrant_entries(giveaway: &Giveaway) -> EntrantEntries {
+        EntrantEntries {
+            answers: giveaway.selection_method == SelectionMethod::SkillContest,
+            admissions: giveaway.hold_until_draw,
+            indexes: giveaway.selection_method == SelectionMethod::Merit,
+        }
+    }
+
+    /// Settle a `FirstCome` or `InstantWin` giveaway whose prizes were not
+    /// all won by `end_time`. Callable by anyone. The winners so far can
+    /// claim as usual and the shares nobody won go back to the creator; if
+    /// nobody won at all, the giveaway is cancelled and the whole prize
+    /// returned.
     ///
-    /// Cancellation is deliberately unavailable after the first entry or
-    /// after winner selection. This prevents a creator from withdrawing a
-    /// prize after participants have begun relying on the campaign.
-    pub fn cancel_giveaway(env: Env, creator: Address, giveaway_id: u64) {
-        creator.require_auth();
+    /// # Panics
+    /// * `Error::InvalidStatus` if the giveaway is not `Active` or uses
+    ///   another selection method
+    /// * `Error::GiveawayStillActive` until `end_time` has passed
+    pub fn close_instant_giveaway(env: Env, giveaway_id: u64) {
+        ensure_not_paused(&env, PauseModule::Refunds);
 
         with_reentrancy_guard(&env, || {
             let giveaway_key = DataKey::Giveaway(giveaway_id);
@@ -197,58 +1096,218 @@ impl GiveawayContract {
                 .persistent()
                 .get(&giveaway_key)
                 .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+            extend_content_ttl(&env, &giveaway_key);
 
-            if giveaway.creator != creator {
-                panic_with_error!(&env, Error::NotCreator);
-            }
-            if giveaway.status != GiveawayStatus::Active || giveaway.participant_count != 0 {
+            if giveaway.status != GiveawayStatus::Active
+                || !matches!(
+                    giveaway.selection_method,
+                    SelectionMethod::FirstCome | SelectionMethod::InstantWin
+                )
+            {
                 panic_with_error!(&env, Error::InvalidStatus);
             }
+            if env.ledger().timestamp() <= giveaway.end_time {
+                panic_with_error!(&env, Error::GiveawayStillActive);
+            }
 
-            // Persist the terminal state before the external token call. Soroban
-            // rolls this write back if the transfer fails.
-            giveaway.status = GiveawayStatus::Cancelled;
-            env.storage().persistent().set(&giveaway_key, &giveaway);
-
-            let token_client = token::Client::new(&env, &giveaway.token);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &giveaway.creator,
-                &giveaway.amount,
-            );
+            if giveaway.winners.is_empty() {
+                Self::refund_creator(&env, &giveaway_key, &mut giveaway);
+            } else {
+                let winners = giveaway.winners.clone();
+                Self::finalize_winners(&env, &giveaway_key, giveaway, winners);
+            }
         })
     }
 
-    fn verify_participant(env: &Env, giveaway: &Giveaway, participant: &Address) {
-        match giveaway.verification_type {
-            1 => {
-                let allowed_key = DataKey::GiveawayAllowlist(giveaway.id, participant.clone());
-                let authorized: bool = env
-                    .storage()
-                    .persistent()
-                    .get(&allowed_key)
-                    .unwrap_or(false);
-                if !authorized {
-                    panic_with_error!(&env, Error::UnauthorizedParticipant);
-                }
+    /// Reveal the correct answer of a skill contest - callable only by the
+    /// creator, after `end_time` and within `REVEAL_WINDOW_SECONDS` of it.
+    /// Entrants then have `REVEAL_WINDOW_SECONDS` to reveal theirs.
+    ///
+    /// # Panics
+    /// * `Error::NotCreator` if `creator` did not create the giveaway
+    /// * `Error::InvalidStatus` if it is not an `Active` skill contest, or
+    ///   the answer is already revealed
+    /// * `Error::GiveawayStillActive` until `end_time` has passed
+    /// * `EntryError::RevealClosed` once the creator's window has passed
+    /// * `EntryError::AnswerMismatch` if `answer` and `salt` do not match
+    ///   the committed `answer_hash`
+    pub fn reveal_contest_answer(
+        env: Env,
+        creator: Address,
+        giveaway_id: u64,
+        answer: Bytes,
+        salt: BytesN<32>,
+    ) {
+        creator.require_auth();
+
+        let (giveaway, contest) = Self::load_contest(&env, giveaway_id);
+        if giveaway.creator != creator {
+            panic_with_error!(&env, Error::NotCreator);
+        }
+        if revealed_answer(&env, giveaway_id).is_some() {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+        let now = env.ledger().timestamp();
+        if now <= giveaway.end_time {
+            panic_with_error!(&env, Error::GiveawayStillActive);
+        }
+        if now > reveals_close_at(&None, giveaway.end_time) {
+            panic_with_error!(&env, EntryError::RevealClosed);
+        }
+        if answer_commitment(&env, &creator, &answer, &salt) != contest.answer_hash {
+            panic_with_error!(&env, EntryError::AnswerMismatch);
+        }
+
+        let answer_key = EntryKey::ContestAnswer(giveaway_id);
+        let revealed = RevealedAnswer {
+            answer: answer.clone(),
+            revealed_at: now,
+        };
+        env.storage().persistent().set(&answer_key, &revealed);
+        extend_persistent(&env, &answer_key);
+
+        ContestAnswerRevealed {
+            giveaway_id,
+            answer,
+        }
+        .publish(&env);
+    }
+
+    /// Reveal `participant`'s committed answer to a skill contest, once the
+    /// creator has revealed the correct one and until
+    /// `REVEAL_WINDOW_SECONDS` after that. Returns whether it is correct;
+    /// correct answers from entrants who are not disqualified count towards
+    /// the winners in the order they are revealed.
+    ///
+    /// # Panics
+    /// * `Error::InvalidStatus` if it is not an `Active` skill contest
+    /// * `EntryError::AnswerNotRevealed` until the creator has revealed
+    /// * `EntryError::RevealClosed` once the window has passed
+    /// * `EntryError::NotEntered` if `participant` has no unrevealed answer
+    /// * `EntryError::AnswerMismatch` if `answer` and `salt` do not match
+    ///   their commitment
+    pub fn reveal_answer(
+        env: Env,
+        participant: Address,
+        giveaway_id: u64,
+        answer: Bytes,
+        salt: BytesN<32>,
+    ) -> bool {
+        participant.require_auth();
+
+        let (giveaway, _) = Self::load_contest(&env, giveaway_id);
+        let revealed = revealed_answer(&env, giveaway_id);
+        let Some(correct_answer) = &revealed else {
+            panic_with_error!(&env, EntryError::AnswerNotRevealed);
+        };
+        if env.ledger().timestamp() > reveals_close_at(&revealed, giveaway.end_time) {
+            panic_with_error!(&env, EntryError::RevealClosed);
+        }
+
+        let commitment_key = EntryKey::AnswerCommitment(giveaway_id, participant.clone());
+        let commitment: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&commitment_key)
+            .unwrap_or_else(|| panic_with_error!(&env, EntryError::NotEntered));
+        if answer_commitment(&env, &participant, &answer, &salt) != commitment {
+            panic_with_error!(&env, EntryError::AnswerMismatch);
+        }
+        env.storage().persistent().remove(&commitment_key);
+
+        let correct =
+            answer == correct_answer.answer && !is_disqualified(&env, giveaway_id, &participant);
+        if correct {
+            add_correct_revealer(&env, giveaway_id, participant.clone());
+        }
+
+        AnswerRevealed {
+            giveaway_id,
+            participant,
+            correct,
+        }
+        .publish(&env);
+        correct
+    }
+
+    /// Pick the winners of a skill contest once revealing has closed.
+    /// Callable by anyone. Winners are the entrants who revealed the correct
+    /// answer: the first `winner_count` of them with
+    /// `ContestOrdering::RevealOrder`, or `winner_count` drawn at random with
+    /// `ContestOrdering::Random`. Unrevealed and wrong answers never win,
+    /// and shares nobody won go back to the creator; with no correct answer
+    /// the giveaway is cancelled and the whole prize returned.
+    ///
+    /// If the creator never revealed the answer, the winners are drawn at
+    /// random from every entrant instead, so withholding it cannot win the
+    /// prize back.
+    ///
+    /// # Panics
+    /// * `Error::InvalidStatus` if it is not an `Active` skill contest
+    /// * `Error::GiveawayStillActive` until revealing has closed
+    /// * `EntryError::ReserveNotMet` if `min_participants` was not met
+    pub fn finalize_contest_winners(env: Env, giveaway_id: u64) {
+        with_reentrancy_guard(&env, || {
+            let (giveaway, contest) = Self::load_contest(&env, giveaway_id);
+            let revealed = revealed_answer(&env, giveaway_id);
+            if env.ledger().timestamp() <= reveals_close_at(&revealed, giveaway.end_time) {
+                panic_with_error!(&env, Error::GiveawayStillActive);
             }
-            2 => {
-                let reputation = ProfileContract::get_reputation(env.clone(), participant.clone());
-                if reputation < giveaway.min_reputation {
-                    panic_with_error!(&env, Error::UnauthorizedParticipant);
-                }
+            if entrant_count(&env, giveaway_id) < giveaway.min_participants {
+                panic_with_error!(&env, EntryError::ReserveNotMet);
             }
-            _ => {}
-        }
+
+            let winners = if revealed.is_some() {
+                let mut correct = Vec::new(&env);
+                for participant in correct_revealers(&env, giveaway_id).iter() {
+                    if !is_disqualified(&env, giveaway_id, &participant)
+                        && Self::still_eligible(&env, &giveaway, &participant)
+                    {
+                        correct.push_back(participant);
+                    }
+                }
+                match contest.ordering {
+                    ContestOrdering::RevealOrder => {
+                        correct.slice(0..giveaway.winner_count.min(correct.len()))
+                    }
+                    ContestOrdering::Random => {
+                        let mut winners = Vec::new(&env);
+                        while winners.len() < giveaway.winner_count && !correct.is_empty() {
+                            let index = env.prng().gen_range::<u64>(0..correct.len() as u64);
+                            winners.push_back(correct.get_unchecked(index as u32));
+                            correct.remove(index as u32);
+                        }
+                        winners
+                    }
+                }
+            } else {
+                let seed = env.prng().gen::<u64>();
+                let draws = giveaway.winner_count.min(eligible_count(&env, giveaway_id));
+                Self::draw_winners(&env, &giveaway, seed, draws)
+            };
+
+            let giveaway_key = DataKey::Giveaway(giveaway_id);
+            Self::finalize_winners(&env, &giveaway_key, giveaway, winners);
+        })
+    }
+
+    /// The correct answer of a skill contest, once the creator revealed it.
+    pub fn get_contest_answer(env: Env, giveaway_id: u64) -> Option<RevealedAnswer> {
+        revealed_answer(&env, giveaway_id)
     }
 
-    pub fn pick_winner(env: Env, giveaway_id: u64) -> Address {
+    /// Draw the winners of an ended `Random` giveaway and make their prizes
+    /// claimable. Returns the first winner, or `None` if no entrant still
+    /// met a `hold_until_draw` giveaway's rules and the prize went back to
+    /// the creator.
+    pub fn pick_winner(env: Env, giveaway_id: u64) -> Option<Address> {
         let giveaway_key = DataKey::Giveaway(giveaway_id);
         let giveaway: Giveaway = env
             .storage()
             .persistent()
             .get(&giveaway_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
 
         if giveaway.selection_method != SelectionMethod::Random {
             panic_with_error!(&env, Error::InvalidStatus);
@@ -257,39 +1316,57 @@ impl GiveawayContract {
         Self::ensure_ready_for_selection(&env, &giveaway);
 
         let random_seed = env.prng().gen::<u64>();
-        let mut selected_indexes: Vec<u32> = Vec::new(&env);
-        let mut winners: Vec<Address> = Vec::new(&env);
-
-        let total = giveaway.participant_count;
-        let target_count = giveaway.winner_count;
-
-        for i in 0..target_count {
-            let mut index = ((random_seed.wrapping_add(i as u64)) % total as u64) as u32;
-            while {
-                let mut duplicate = false;
-                for picked in selected_indexes.iter() {
-                    if picked == index {
-                        duplicate = true;
-                        break;
-                    }
-                }
-                duplicate
-            } {
-                index = (index + 1) % total;
-            }
+        let winners = Self::draw_winners(&env, &giveaway, random_seed, giveaway.winner_count);
 
-            selected_indexes.push_back(index);
+        Self::finalize_winners(&env, &giveaway_key, giveaway, winners)
+    }
 
-            let participant_key = DataKey::ParticipantIndex(giveaway_id, index);
-            let winner_address: Address = env
-                .storage()
-                .persistent()
-                .get(&participant_key)
-                .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidIndex));
-            winners.push_back(winner_address.clone());
+    /// Make up to `draws` random draws for `seed`, stopping early once
+    /// nobody is left to draw.
+    fn draw_winners(env: &Env, giveaway: &Giveaway, seed: u64, draws: u32) -> Vec<Address> {
+        let total = participant_count(env, giveaway.id);
+        let mut winners = Vec::new(env);
+        let mut passed_over = Vec::new(env);
+        for draw in 0..draws {
+            match Self::draw_winner(env, giveaway, seed, draw, total, &winners, &mut passed_over) {
+                Some(winner) => winners.push_back(winner),
+                None => break,
+            }
         }
+        winners
+    }
 
-        Self::finalize_winners(&env, &giveaway_key, giveaway, winners)
+    /// The `draw`-th random winner: the participant at `(seed + draw) % total`,
+    /// or the next one along who has not left, been disqualified or already
+    /// been drawn, and who still meets a `hold_until_draw` giveaway's rules.
+    /// Entrants found not to are added to `passed_over`, so later draws skip
+    /// them without checking again. `None` once every slot has been passed.
+    fn draw_winner(
+        env: &Env,
+        giveaway: &Giveaway,
+        seed: u64,
+        draw: u32,
+        total: u32,
+        winners: &Vec<Address>,
+        passed_over: &mut Vec<Address>,
+    ) -> Option<Address> {
+        let mut index = (seed.wrapping_add(draw as u64) % total as u64) as u32;
+        for _ in 0..total {
+            let candidate = participant_at(env, giveaway.id, index)
+                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidIndex));
+            if !winners.contains(&candidate)
+                && !passed_over.contains(&candidate)
+                && has_entered(env, giveaway.id, &candidate)
+                && !is_disqualified(env, giveaway.id, &candidate)
+            {
+                if Self::still_eligible(env, giveaway, &candidate) {
+                    return Some(candidate);
+                }
+                passed_over.push_back(candidate);
+            }
+            index = (index + 1) % total;
+        }
+        None
     }
 
     /// Splits `amount` evenly across `winner_count` winners, with the winner
@@ -314,6 +1391,56 @@ impl GiveawayContract {
         }
     }
 
+    /// The fee rate `creator` is charged on a new giveaway: their override if
+    /// the admin set one, otherwise the highest reputation tier they reach,
+    /// otherwise the base fee.
+    fn creator_fee_bps(env: &Env, creator: &Address) -> u32 {
+        if let Some(fee_bps) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&DataKey::FeeOverride(creator.clone()))
+        {
+            return fee_bps;
+        }
+
+        let fee_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Fee)
+            .unwrap_or(DEFAULT_FEE_BPS);
+
+        let tiers: Vec<FeeTier> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeSchedule)
+            .unwrap_or_else(|| Vec::new(env));
+        if tiers.is_empty() {
+            return fee_bps;
+        }
+        let reputation = ProfileContract::get_reputation(env.clone(), creator.clone());
+        scheduled_fee_bps(&tiers, fee_bps, reputation)
+    }
+
+    /// Protocol fee owed on `amount` at `fee_bps` basis points.
+    fn fee_amount(env: &Env, amount: i128, fee_bps: u32) -> i128 {
+        amount
+            .checked_mul(fee_bps as i128)
+            .and_then(|v| v.checked_div(10_000))
+            .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow))
+    }
+
+    /// The fee withheld from the winner at `index` when they claim. Zero for
+    /// `CreatorPays` giveaways, whose fee was collected at creation.
+    fn winner_share_and_fee(env: &Env, giveaway: &Giveaway, index: u32) -> (i128, i128) {
+        let gross_share =
+            Self::winner_gross_share(env, giveaway.amount, giveaway.winner_count, index);
+        let fee = match giveaway.fee_mode {
+            FeeMode::WinnerPays => Self::fee_amount(env, gross_share, giveaway.fee_bps),
+            FeeMode::CreatorPays => 0,
+        };
+        (gross_share, fee)
+    }
+
     fn find_winner_index(winners: &Vec<Address>, winner: &Address) -> Option<u32> {
         for (index, candidate) in winners.iter().enumerate() {
             if candidate == *winner {
@@ -342,6 +1469,7 @@ impl GiveawayContract {
     /// while the giveaway is `Claimable` and before `claim_deadline`.
     pub fn claim_prize(env: Env, giveaway_id: u64, winner: Address) {
         winner.require_auth();
+        ensure_not_paused(&env, PauseModule::Claims);
 
         with_reentrancy_guard(&env, || {
             let giveaway_key = DataKey::Giveaway(giveaway_id);
@@ -350,6 +1478,7 @@ impl GiveawayContract {
                 .persistent()
                 .get(&giveaway_key)
                 .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+            extend_content_ttl(&env, &giveaway_key);
 
             if giveaway.status != GiveawayStatus::Claimable {
                 panic_with_error!(&env, Error::InvalidStatus);
@@ -371,24 +1500,20 @@ impl GiveawayContract {
                 panic_with_error!(&env, Error::AlreadyClaimed);
             }
 
-            let fee_key = DataKey::Fee;
-            let fee_bps: u32 = env.storage().instance().get(&fee_key).unwrap_or(100); // Default to 100 bps (1%)
-
-            let gross_share =
-                Self::winner_gross_share(&env, giveaway.amount, giveaway.winner_count, index);
-            let fee_amount = gross_share
-                .checked_mul(fee_bps as i128)
-                .and_then(|v| v.checked_div(10_000))
-                .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
+            let (gross_share, fee_amount) = Self::winner_share_and_fee(&env, &giveaway, index);
             let net_amount = gross_share
                 .checked_sub(fee_amount)
                 .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
 
             let token_client = token::Client::new(&env, &giveaway.token);
             token_client.transfer(&env.current_contract_address(), &winner, &net_amount);
-            Self::add_collected_fees(&env, &giveaway.token, fee_amount);
+            decrease_liabilities(&env, &giveaway.token, net_amount);
+            if fee_amount > 0 {
+                Self::add_collected_fees(&env, &giveaway.token, fee_amount);
+            }
 
             env.storage().persistent().set(&claimed_key, &true);
+            extend_persistent(&env, &claimed_key);
             giveaway.claimed_count += 1;
 
             if giveaway.claimed_count == giveaway.winners.len() {
@@ -404,6 +1529,7 @@ impl GiveawayContract {
     /// giveaway. Shares already claimed by winners are untouched.
     pub fn recover_unclaimed_prize(env: Env, giveaway_id: u64, caller: Address) {
         caller.require_auth();
+        ensure_not_paused(&env, PauseModule::Refunds);
 
         with_reentrancy_guard(&env, || {
             let giveaway_key = DataKey::Giveaway(giveaway_id);
@@ -412,6 +1538,7 @@ impl GiveawayContract {
                 .persistent()
                 .get(&giveaway_key)
                 .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+            extend_content_ttl(&env, &giveaway_key);
 
             Self::ensure_creator_or_admin(&env, &caller, &giveaway);
 
@@ -422,27 +1549,7 @@ impl GiveawayContract {
                 panic_with_error!(&env, Error::ClaimWindowNotExpired);
             }
 
-            let mut recoverable = 0i128;
-            for (index, winner) in giveaway.winners.iter().enumerate() {
-                let claimed_key = DataKey::Claimed(giveaway_id, winner.clone());
-                let already_claimed: bool = env
-                    .storage()
-                    .persistent()
-                    .get(&claimed_key)
-                    .unwrap_or(false);
-                if !already_claimed {
-                    let gross_share = Self::winner_gross_share(
-                        &env,
-                        giveaway.amount,
-                        giveaway.winner_count,
-                        index as u32,
-                    );
-                    recoverable = recoverable
-                        .checked_add(gross_share)
-                        .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
-                }
-            }
-
+            let recoverable = Self::unclaimed_shares(&env, &giveaway);
             if recoverable > 0 {
                 let token_client = token::Client::new(&env, &giveaway.token);
                 token_client.transfer(
@@ -450,11 +1557,48 @@ impl GiveawayContract {
                     &giveaway.creator,
                     &recoverable,
                 );
+                decrease_liabilities(&env, &giveaway.token, recoverable);
+            }
+
+            giveaway.status = GiveawayStatus::Completed;
+            env.storage().persistent().set(&giveaway_key, &giveaway);
+        })
+    }
+
+    /// Sum of the gross shares of winners of `giveaway` who have not claimed.
+    fn unclaimed_shares(env: &Env, giveaway: &Giveaway) -> i128 {
+        let mut unclaimed = 0i128;
+        for (index, winner) in giveaway.winners.iter().enumerate() {
+            let claimed_key = DataKey::Claimed(giveaway.id, winner.clone());
+            let already_claimed: bool = env
+                .storage()
+                .persistent()
+                .get(&claimed_key)
+                .unwrap_or(false);
+            if !already_claimed {
+                let gross_share = Self::winner_gross_share(
+                    env,
+                    giveaway.amount,
+                    giveaway.winner_count,
+                    index as u32,
+                );
+                unclaimed = unclaimed
+                    .checked_add(gross_share)
+                    .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
             }
+        }
+        unclaimed
+    }
 
-            giveaway.status = GiveawayStatus::Completed;
-            env.storage().persistent().set(&giveaway_key, &giveaway);
-        })
+    /// How much of `giveaway`'s escrow the contract still holds for its
+    /// creator or winners, not counting fees already moved to
+    /// `CollectedFees`. Used by `migrate` to seed `Liabilities`.
+    pub(crate) fn unpaid_escrow(env: &Env, giveaway: &Giveaway) -> i128 {
+        match giveaway.status {
+            GiveawayStatus::Completed | GiveawayStatus::Cancelled => 0,
+            GiveawayStatus::Claimable => Self::unclaimed_shares(env, giveaway),
+            _ => giveaway.amount,
+        }
     }
 
     pub fn init(env: Env, admin: Address, fee_bps: u32) {
@@ -471,6 +1615,11 @@ impl GiveawayContract {
         // Store fee basis points
         let fee_key = DataKey::Fee;
         env.storage().instance().set(&fee_key, &fee_bps);
+
+        // Fresh deployments start on the current layout; nothing to migrate.
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &CURRENT_SCHEMA_VERSION);
     }
 
     fn generate_id(env: &Env) -> u64 {
@@ -486,42 +1635,23 @@ impl GiveawayContract {
         counter
     }
 
-    /// Withdraw collected fees for a specific token - callable only by Admin
-    /// Transfers all accumulated fees for the specified token to the admin address
+    /// Withdraw collected fees for a specific token - callable by a Treasurer
+    /// Transfers all accumulated fees for the specified token to the caller
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `caller` - The treasurer (or admin) receiving the fees
     /// * `token` - The token address to withdraw fees for
     ///
     /// # Panics
-    /// Panics if called by non-admin address
-    pub fn withdraw_fees(env: Env, token: Address) {
-        // 1. Admin auth
-        let admin_key = DataKey::Admin;
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&admin_key)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        // 2. Read 'CollectedFees(token)' amount
-        let collected_fees_key = DataKey::CollectedFees(token.clone());
-        let fee_amount: i128 = env
-            .storage()
-            .persistent()
-            .get(&collected_fees_key)
-            .unwrap_or(0);
-
-        // Only proceed if there are fees to withdraw
-        if fee_amount > 0 {
-            // 3. Transfer that amount to Admin
-            let token_client = token::Client::new(&env, &token);
-            token_client.transfer(&env.current_contract_address(), &admin, &fee_amount);
-
-            // 4. Set 'CollectedFees(token)' to 0
-            env.storage().persistent().set(&collected_fees_key, &0i128);
-        }
+    /// Panics if `caller` does not hold the Treasurer role, or with
+    /// `Error::MultisigRequired` once a multisig guards this action
+    pub fn withdraw_fees(env: Env, caller: Address, token: Address) {
+        // 1. Treasurer auth
+        check_role(&env, &caller, Role::Treasurer);
+        ensure_single_key_allowed(&env);
+
+        Self::execute_withdraw_fees(&env, token, caller);
     }
 
     // Helper function to ensure the giveaway is ready for selection
@@ -532,14 +1662,69 @@ impl GiveawayContract {
         if env.ledger().timestamp() <= giveaway.end_time {
             panic_with_error!(env, Error::GiveawayStillActive);
         }
-        if giveaway.participant_count == 0 {
+        if entrant_count(env, giveaway.id) < giveaway.min_participants {
+            panic_with_error!(env, EntryError::ReserveNotMet);
+        }
+        let eligible_count = eligible_count(env, giveaway.id);
+        if eligible_count == 0 {
             panic_with_error!(env, Error::NoParticipants);
         }
-        if giveaway.participant_count < giveaway.winner_count {
+        if eligible_count < giveaway.winner_count {
             panic_with_error!(env, Error::InsufficientParticipants);
         }
     }
 
+    /// Load an `Active` skill contest and its `SkillContest` settings.
+    fn load_contest(env: &Env, giveaway_id: u64) -> (Giveaway, SkillContest) {
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::GiveawayNotFound));
+        extend_content_ttl(env, &giveaway_key);
+
+        if giveaway.status != GiveawayStatus::Active
+            || giveaway.selection_method != SelectionMethod::SkillContest
+        {
+            panic_with_error!(env, Error::InvalidStatus);
+        }
+        let contest = env
+            .storage()
+            .persistent()
+            .get(&EntryKey::Contest(giveaway_id))
+            .unwrap_or_else(|| panic_with_error!(env, Error::InvalidStatus));
+        (giveaway, contest)
+    }
+
+    /// Cancel `giveaway` and transfer its escrowed prize back to the creator.
+    fn refund_creator(env: &Env, giveaway_key: &DataKey, giveaway: &mut Giveaway) {
+        // Persist the terminal state before the external token call. Soroban
+        // rolls this write back if the transfer fails.
+        giveaway.status = GiveawayStatus::Cancelled;
+        env.storage().persistent().set(giveaway_key, giveaway);
+
+        let refund = giveaway
+            .amount
+            .checked_add(Self::take_pending_fee(env, giveaway.id))
+            .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
+        let token_client = token::Client::new(env, &giveaway.token);
+        token_client.transfer(&env.current_contract_address(), &giveaway.creator, &refund);
+        decrease_liabilities(env, &giveaway.token, refund);
+    }
+
+    /// Remove and return the `CreatorPays` fee held for `giveaway_id`. Zero
+    /// for `WinnerPays` giveaways and for those created before the fee was
+    /// held, whose fee went to `CollectedFees` at creation.
+    fn take_pending_fee(env: &Env, giveaway_id: u64) -> i128 {
+        let fee_key = EntryKey::PendingFee(giveaway_id);
+        let fee = env.storage().persistent().get(&fee_key).unwrap_or(0);
+        if fee > 0 {
+            env.storage().persistent().remove(&fee_key);
+        }
+        fee
+    }
+
     // Helper function to check if caller is creator or admin
     fn ensure_creator_or_admin(env: &Env, caller: &Address, giveaway: &Giveaway) {
         if *caller == giveaway.creator {
@@ -556,17 +1741,22 @@ impl GiveawayContract {
     }
 
     // Helper function to validate manual winners are all valid participants
-    fn validate_manual_winners(env: &Env, giveaway_id: u64, winners: &Vec<Address>) {
+    fn validate_manual_winners(env: &Env, giveaway: &Giveaway, winners: &Vec<Address>) {
         for winner in winners.iter() {
-            let has_entered_key = DataKey::HasEntered(giveaway_id, winner.clone());
-            let has_entered: bool = env
-                .storage()
-                .persistent()
-                .get(&has_entered_key)
-                .unwrap_or(false);
-            if !has_entered {
+            if !has_entered(env, giveaway.id, &winner) {
                 panic_with_error!(env, Error::InvalidIndex);
             }
+            if is_disqualified(env, giveaway.id, &winner) {
+                panic_with_error!(env, EntryError::ParticipantDisqualified);
+            }
+            if giveaway.hold_until_draw {
+                let mut admitted_by = Self::admitted_by(env, giveaway.id, &winner);
+                if let Err(error) =
+                    Self::check_eligibility(env, giveaway, &winner, None, &mut admitted_by)
+                {
+                    panic_with_error!(env, error);
+                }
+            }
         }
         // Check for duplicates
         for i in 0..winners.len() {
@@ -578,66 +1768,249 @@ impl GiveawayContract {
         }
     }
 
-    // Helper function to select winners by merit (reputation)
-    fn select_merit_winners(
+    /// Snapshot `participant`'s reputation into the giveaway's bounded
+    /// leaderboard. Called on every merit entry, so finalization is O(K).
+    ///
+    /// The leaderboard is started by the first entrant. One that is missing
+    /// later, for a giveaway entered before leaderboards existed or after
+    /// more members left or were disqualified than its runners-up could
+    /// replace, stays missing: selection then ranks every entrant instead.
+    fn record_merit_entry(env: &Env, giveaway: &Giveaway, participant: &Address, entry_index: u32) {
+        let leaderboard_key = DataKey::MeritLeaderboard(giveaway.id);
+        let stored: Option<Vec<MeritEntry>> = env.storage().persistent().get(&leaderboard_key);
+        let mut leaderboard = match stored {
+            Some(leaderboard) => leaderboard,
+            None if entry_index == 0 => Vec::new(env),
+            None => return,
+        };
+
+        let entry = MeritEntry {
+            participant: participant.clone(),
+            reputation: ProfileContract::get_reputation(env.clone(), participant.clone()),
+            entry_index,
+        };
+        let capacity = giveaway.winner_count + MERIT_LEADERBOARD_BUFFER;
+        if Self::insert_ranked(&mut leaderboard, entry, capacity) {
+            env.storage()
+                .persistent()
+                .set(&leaderboard_key, &leaderboard);
+            extend_persistent(env, &leaderboard_key);
+        }
+    }
+
+    /// Record `participant`, who just entered a `FirstCome` or `InstantWin`
+    /// giveaway, as a winner. The last prize moves the giveaway to
+    /// `Claimable` straight away.
+    fn award_on_entry(
         env: &Env,
-        giveaway_id: u64,
-        winner_count: u32,
-        participant_count: u32,
-    ) -> Vec<Address> {
-        let mut participants_with_reputation = Vec::new(env);
-        for i in 0..participant_count {
-            let participant_key = DataKey::ParticipantIndex(giveaway_id, i);
-            let participant: Address = env.storage().persistent().get(&participant_key).unwrap();
-            let reputation = ProfileContract::get_reputation(env.clone(), participant.clone());
-            participants_with_reputation.push_back((participant, reputation));
-        }
-        // Sort by reputation descending (very simple sort)
-        let mut sorted: Vec<(Address, u64)> = Vec::new(env);
-        for pr in participants_with_reputation.iter() {
-            let pr_clone = pr.clone();
-            let mut inserted = false;
-            for i in 0..sorted.len() {
-                let existing = sorted.get_unchecked(i);
-                if pr_clone.1 > existing.1 {
-                    let mut temp = Vec::new(env);
-                    for j in 0..i {
-                        temp.push_back(sorted.get_unchecked(j));
-                    }
-                    temp.push_back(pr_clone.clone());
-                    for j in i..sorted.len() {
-                        temp.push_back(sorted.get_unchecked(j));
-                    }
-                    sorted = temp;
-                    inserted = true;
+        giveaway_key: &DataKey,
+        mut giveaway: Giveaway,
+        participant: Address,
+    ) {
+        PrizeWonOnEntry {
+            giveaway_id: giveaway.id,
+            winner: participant.clone(),
+        }
+        .publish(env);
+
+        let mut winners = giveaway.winners.clone();
+        winners.push_back(participant);
+        if winners.len() >= giveaway.winner_count {
+            Self::finalize_winners(env, giveaway_key, giveaway, winners);
+        } else {
+            giveaway.winners = winners;
+            env.storage().persistent().set(giveaway_key, &giveaway);
+        }
+    }
+
+    /// Whether the entrant who joins `entrants` others wins an `InstantWin`
+    /// prize. Each entry wins with the odds of the prizes left over the
+    /// entries left, like a draw without replacement, so every prize is
+    /// handed out if the giveaway fills up.
+    fn roll_instant_win(env: &Env, giveaway: &Giveaway, entrants: u32) -> bool {
+        let prizes_left = giveaway.winner_count - giveaway.winners.len();
+        // `create_scheduled_giveaway` requires a cap, and entry checked that
+        // this entrant fits under it.
+        let entries_left = giveaway.max_participants.unwrap_or(entrants + 1) - entrants;
+        env.prng().gen_range::<u64>(0..entries_left as u64) < prizes_left as u64
+    }
+
+    /// Put back into play a prize `participant` won on entry, if any.
+    fn release_prize(
+        env: &Env,
+        giveaway_key: &DataKey,
+        giveaway: &mut Giveaway,
+        participant: &Address,
+    ) {
+        if let Some(index) = giveaway.winners.first_index_of(participant) {
+            giveaway.winners.remove(index);
+            env.storage().persistent().set(giveaway_key, giveaway);
+        }
+    }
+
+    /// Take `participant` off the merit leaderboard, moving the runners-up
+    /// behind them up. Everyone left off the leaderboard ranks below all who
+    /// remain on it, so it only has to go once it is shorter than
+    /// `winner_count` while other entrants are still eligible: the next best
+    /// is then unknown, and selection ranks everyone again.
+    fn remove_from_leaderboard(env: &Env, giveaway: &Giveaway, participant: &Address) {
+        let leaderboard_key = DataKey::MeritLeaderboard(giveaway.id);
+        let Some(mut leaderboard) = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<MeritEntry>>(&leaderboard_key)
+        else {
+            return;
+        };
+        let Some(index) = leaderboard
+            .iter()
+            .position(|entry| entry.participant == *participant)
+        else {
+            return;
+        };
+
+        leaderboard.remove(index as u32);
+        if leaderboard.len() < giveaway.winner_count
+            && eligible_count(env, giveaway.id) > leaderboard.len()
+        {
+            env.storage().persistent().remove(&leaderboard_key);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&leaderboard_key, &leaderboard);
+            extend_persistent(env, &leaderboard_key);
+        }
+    }
+
+    /// Insert `entry` into a leaderboard ordered by reputation, highest first,
+    /// keeping at most `capacity` entries. Ties go to the earlier entry
+    /// index, which an entrant who left and came back keeps. Returns whether
+    /// the leaderboard changed.
+    fn insert_ranked(leaderboard: &mut Vec<MeritEntry>, entry: MeritEntry, capacity: u32) -> bool {
+        let mut position = leaderboard.len();
+        for (index, existing) in leaderboard.iter().enumerate() {
+            if entry.reputation > existing.reputation
+                || (entry.reputation == existing.reputation
+                    && entry.entry_index < existing.entry_index)
+            {
+                position = index as u32;
+                break;
+            }
+        }
+        if position >= capacity {
+            return false;
+        }
+
+        leaderboard.insert(position, entry);
+        if leaderboard.len() > capacity {
+            leaderboard.pop_back();
+        }
+        true
+    }
+
+    /// The merit winners, best first: the top `winner_count` of the
+    /// leaderboard who still meet a `hold_until_draw` giveaway's rules.
+    /// Giveaways entered before the leaderboard existed, or that lost it,
+    /// have none stored, and one whose runners-up cannot make up for those
+    /// passed over is not enough; the leaderboard is then rebuilt from
+    /// current reputations in one O(n * K) pass.
+    fn merit_winners(env: &Env, giveaway: &Giveaway) -> Vec<Address> {
+        let leaderboard_key = DataKey::MeritLeaderboard(giveaway.id);
+        if let Some(leaderboard) = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<MeritEntry>>(&leaderboard_key)
+        {
+            let mut winners = Vec::new(env);
+            let mut passed_over = 0;
+            for entry in leaderboard.iter() {
+                if winners.len() == giveaway.winner_count {
                     break;
                 }
+                if Self::still_eligible(env, giveaway, &entry.participant) {
+                    winners.push_back(entry.participant);
+                } else {
+                    passed_over += 1;
+                }
+            }
+            let ranked = winners.len() + passed_over;
+            if winners.len() == giveaway.winner_count || eligible_count(env, giveaway.id) <= ranked
+            {
+                return winners;
+            }
+        }
+
+        let mut rebuilt = Vec::new(env);
+        let count = participant_count(env, giveaway.id);
+        Self::rank_participants(env, giveaway, &mut rebuilt, 0, count);
+        Self::leaderboard_addresses(env, &rebuilt)
+    }
+
+    /// Rank the participants at entry indexes `start..end` into `leaderboard`
+    /// by their current reputation, passing over empty slots, disqualified
+    /// entrants and those who no longer meet a `hold_until_draw` giveaway's
+    /// rules. Only entrants who would make the leaderboard are re-checked.
+    fn rank_participants(
+        env: &Env,
+        giveaway: &Giveaway,
+        leaderboard: &mut Vec<MeritEntry>,
+        start: u32,
+        end: u32,
+    ) {
+        for index in start..end {
+            let participant = participant_at(env, giveaway.id, index)
+                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidIndex));
+            if !has_entered(env, giveaway.id, &participant)
+                || is_disqualified(env, giveaway.id, &participant)
+            {
+                continue;
             }
-            if !inserted {
-                sorted.push_back(pr_clone);
+            let reputation = ProfileContract::get_reputation(env.clone(), participant.clone());
+            let ranks = leaderboard.len() < giveaway.winner_count
+                || leaderboard
+                    .last()
+                    .is_some_and(|last| reputation > last.reputation);
+            if !ranks || !Self::still_eligible(env, giveaway, &participant) {
+                continue;
             }
+            let entry = MeritEntry {
+                participant,
+                reputation,
+                entry_index: index,
+            };
+            Self::insert_ranked(leaderboard, entry, giveaway.winner_count);
         }
-        // Take top N winners
+    }
+
+    fn leaderboard_addresses(env: &Env, leaderboard: &Vec<MeritEntry>) -> Vec<Address> {
         let mut winners = Vec::new(env);
-        for i in 0..winner_count {
-            winners.push_back(sorted.get_unchecked(i).0.clone());
+        for entry in leaderboard.iter() {
+            winners.push_back(entry.participant);
         }
         winners
     }
 
-    // Helper function to finalize winners and emit events
+    // Helper function to finalize winners and emit events. With no winners,
+    // which only happens once every entrant of a `hold_until_draw` giveaway
+    // was passed over, the giveaway is cancelled and the prize refunded.
     fn finalize_winners(
         env: &Env,
         giveaway_key: &DataKey,
         mut giveaway: Giveaway,
         winners: Vec<Address>,
-    ) -> Address {
-        // Emit winner events. `prize_amount` is each winner's gross share
-        // (before the per-claim fee deduction) — an estimate for indexers,
-        // since the authoritative payout happens in `claim_prize`.
+    ) -> Option<Address> {
+        if winners.is_empty() {
+            Self::refund_creator(env, giveaway_key, &mut giveaway);
+            return None;
+        }
+
+        // Emit winner events. The fee rate is fixed at creation, so
+        // `prize_amount` is exactly what `claim_prize` will transfer.
         for (index, winner) in winners.iter().enumerate() {
-            let prize_amount =
-                Self::winner_gross_share(env, giveaway.amount, giveaway.winner_count, index as u32);
+            let (gross_share, fee) = Self::winner_share_and_fee(env, &giveaway, index as u32);
+            let prize_amount = gross_share
+                .checked_sub(fee)
+                .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
             GiveawayWinnerSelected {
                 winner: winner.clone(),
                 giveaway_id: giveaway.id,
@@ -646,14 +2019,75 @@ impl GiveawayContract {
             .publish(env);
         }
 
+        Self::refund_unfilled_shares(env, &giveaway, winners.len());
+        let upfront_fee = Self::take_pending_fee(env, giveaway.id);
+        if upfront_fee > 0 {
+            Self::add_collected_fees(env, &giveaway.token, upfront_fee);
+        }
+
         giveaway.winners = winners.clone();
         giveaway.status = GiveawayStatus::Claimable;
         giveaway.claim_deadline = env.ledger().timestamp() + CLAIM_WINDOW_SECONDS;
         env.storage().persistent().set(giveaway_key, &giveaway);
 
-        winners
-            .first()
-            .unwrap_or_else(|| panic_with_error!(env, Error::NoParticipants))
+        winners.first()
+    }
+
+    /// Whether `participant` still meets a `hold_until_draw` giveaway's
+    /// rules, which they may not once, say, they sold the gating token after
+    /// entering. Publishes `WinnerDisqualified` for those passed over.
+    /// Always `true` for other giveaways, whose entrants were checked once
+    /// at entry.
+    fn still_eligible(env: &Env, giveaway: &Giveaway, participant: &Address) -> bool {
+        if !giveaway.hold_until_draw {
+            return true;
+        }
+        let mut admitted_by = Self::admitted_by(env, giveaway.id, participant);
+        if Self::check_eligibility(env, giveaway, participant, None, &mut admitted_by).is_ok() {
+            return true;
+        }
+        WinnerDisqualified {
+            giveaway_id: giveaway.id,
+            participant: participant.clone(),
+        }
+        .publish(env);
+        false
+    }
+
+    /// The credential rules that admitted `participant`. Credential rules
+    /// hold at the draw only if they admitted the entrant; those without an
+    /// `AdmittedBy` record, who entered before it was kept, are taken to
+    /// meet all of them.
+    fn admitted_by(env: &Env, giveaway_id: u64, participant: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&EntryKey::AdmittedBy(giveaway_id, participant.clone()))
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Return to the creator the shares of winner slots left empty, indexes
+    /// `filled..winner_count`, when fewer entrants qualified than there are
+    /// prizes.
+    fn refund_unfilled_shares(env: &Env, giveaway: &Giveaway, filled: u32) {
+        let mut refund = 0i128;
+        for index in filled..giveaway.winner_count {
+            refund = refund
+                .checked_add(Self::winner_gross_share(
+                    env,
+                    giveaway.amount,
+                    giveaway.winner_count,
+                    index,
+                ))
+                .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
+        }
+        if refund > 0 {
+            token::Client::new(env, &giveaway.token).transfer(
+                &env.current_contract_address(),
+                &giveaway.creator,
+                &refund,
+            );
+            decrease_liabilities(env, &giveaway.token, refund);
+        }
     }
 
     /// Finalize a giveaway with manually selected winners
@@ -677,6 +2111,7 @@ impl GiveawayContract {
             .persistent()
             .get(&giveaway_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
 
         // Validate
         Self::ensure_creator_or_admin(&env, &caller, &giveaway);
@@ -690,9 +2125,10 @@ impl GiveawayContract {
             panic_with_error!(&env, Error::InvalidWinnerCount);
         }
 
-        Self::validate_manual_winners(&env, giveaway_id, &winners);
+        Self::validate_manual_winners(&env, &giveaway, &winners);
 
         Self::finalize_winners(&env, &giveaway_key, giveaway, winners)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoParticipants))
     }
 
     /// Finalize a giveaway with merit-based selected winners (by reputation)
@@ -701,7 +2137,11 @@ impl GiveawayContract {
     /// * `env` - The contract environment
     /// * `caller` - The address calling this function (must be creator or admin)
     /// * `giveaway_id` - The ID of the giveaway to finalize
-    pub fn finalize_merit_winners(env: Env, caller: Address, giveaway_id: u64) -> Address {
+    ///
+    /// Returns the top winner, or `None` if no entrant still met a
+    /// `hold_until_draw` giveaway's rules and the prize went back to the
+    /// creator.
+    pub fn finalize_merit_winners(env: Env, caller: Address, giveaway_id: u64) -> Option<Address> {
         caller.require_auth();
 
         let giveaway_key = DataKey::Giveaway(giveaway_id);
@@ -710,6 +2150,7 @@ impl GiveawayContract {
             .persistent()
             .get(&giveaway_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
 
         // Validate
         Self::ensure_creator_or_admin(&env, &caller, &giveaway);
@@ -719,12 +2160,280 @@ impl GiveawayContract {
             panic_with_error!(&env, Error::InvalidStatus);
         }
 
-        let winners = Self::select_merit_winners(
-            &env,
-            giveaway_id,
-            giveaway.winner_count,
-            giveaway.participant_count,
-        );
+        let winners = Self::merit_winners(&env, &giveaway);
         Self::finalize_winners(&env, &giveaway_key, giveaway, winners)
     }
+
+    /// Start a winner selection that is split across several transactions,
+    /// for giveaways too large for `pick_winner` or `finalize_merit_winners`.
+    /// Callable by anyone once the giveaway has ended.
+    ///
+    /// Fixes the randomness and locks the giveaway in `Selecting` until
+    /// `complete_selection`. `KEEPER_REWARD_BPS` of the prize is set aside
+    /// and shared between the callers of `continue_selection` and
+    /// `complete_selection`; winners split the rest.
+    ///
+    /// Random giveaways draw the same winners `pick_winner` would for the
+    /// fixed seed. Merit giveaways without a stored leaderboard are ranked
+    /// `SELECTION_BATCH_SIZE` participants at a time. For `hold_until_draw`
+    /// giveaways each batch re-checks the entrants it draws or ranks, so
+    /// `complete_selection` re-checks nobody.
+    ///
+    /// Only giveaways with more than one batch of work may use it, so the
+    /// reward never comes out of a prize that `pick_winner` or
+    /// `finalize_merit_winners` could settle in a single call.
+    ///
+    /// # Panics
+    /// * `Error::InvalidStatus` for manual giveaways
+    /// * `Error::SelectionTooSmall` if the selection fits one batch,
+    ///   including merit giveaways with a stored leaderboard
+    /// * the errors of `pick_winner` if the giveaway is not ready for selection
+    pub fn begin_selection(env: Env, giveaway_id: u64) {
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let mut giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GiveawayNotFound));
+        extend_content_ttl(&env, &giveaway_key);
+
+        Self::ensure_ready_for_selection(&env, &giveaway);
+
+        let total = match giveaway.selection_method {
+            SelectionMethod::Random => giveaway.winner_count,
+            SelectionMethod::Merit => {
+                if env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::MeritLeaderboard(giveaway_id))
+                {
+                    0
+                } else {
+                    participant_count(&env, giveaway_id)
+                }
+            }
+            SelectionMethod::Manual
+            | SelectionMethod::FirstCome
+            | SelectionMethod::InstantWin
+            | SelectionMethod::SkillContest => panic_with_error!(&env, Error::InvalidStatus),
+        };
+        if total <= SELECTION_BATCH_SIZE {
+            panic_with_error!(&env, Error::SelectionTooSmall);
+        }
+
+        let reward = Self::fee_amount(&env, giveaway.amount, KEEPER_REWARD_BPS);
+        giveaway.amount -= reward;
+        giveaway.status = GiveawayStatus::Selecting;
+        env.storage().persistent().set(&giveaway_key, &giveaway);
+
+        let state = SelectionState {
+            seed: env.prng().gen::<u64>(),
+            total,
+            processed: 0,
+            winners: Vec::new(&env),
+            passed_over: Vec::new(&env),
+            leaderboard: Vec::new(&env),
+            reward_remaining: reward,
+            steps_remaining: total.div_ceil(SELECTION_BATCH_SIZE) + 1,
+        };
+        Self::store_selection_state(&env, giveaway_id, &state);
+
+        SelectionStarted { giveaway_id, total }.publish(&env);
+    }
+
+    /// Process the next `SELECTION_BATCH_SIZE` units of a selection started
+    /// by `begin_selection` and pay `keeper` their share of the reward.
+    /// Callable by anyone. Returns `true` once everything is processed and
+    /// `complete_selection` can be called.
+    ///
+    /// # Panics
+    /// * `Error::InvalidStatus` if the giveaway is not `Selecting`, or if
+    ///   nothing is left to process
+    pub fn continue_selection(env: Env, keeper: Address, giveaway_id: u64) -> bool {
+        keeper.require_auth();
+
+        with_reentrancy_guard(&env, || {
+            let (giveaway, mut state) = Self::load_selection(&env, giveaway_id);
+            if state.processed >= state.total {
+                panic_with_error!(&env, Error::InvalidStatus);
+            }
+
+            let end = (state.processed + SELECTION_BATCH_SIZE).min(state.total);
+            match giveaway.selection_method {
+                SelectionMethod::Random => {
+                    let total = participant_count(&env, giveaway_id);
+                    for draw in state.processed..end {
+                        if let Some(winner) = Self::draw_winner(
+                            &env,
+                            &giveaway,
+                            state.seed,
+                            draw,
+                            total,
+                            &state.winners,
+                            &mut state.passed_over,
+                        ) {
+                            state.winners.push_back(winner);
+                        }
+                    }
+                }
+                _ => {
+                    Self::rank_participants(
+                        &env,
+                        &giveaway,
+                        &mut state.leaderboard,
+                        state.processed,
+                        end,
+                    );
+                }
+            }
+            state.processed = end;
+
+            let reward = Self::take_keeper_share(&mut state);
+            Self::store_selection_state(&env, giveaway_id, &state);
+            Self::pay_keeper(&env, &giveaway, &keeper, reward);
+
+            state.processed == state.total
+        })
+    }
+
+    /// Finish a selection once `continue_selection` has processed every
+    /// batch: finalize the winners and pay `keeper` the rest of the reward.
+    /// Callable by anyone. Returns the first winner, or `None` if nobody
+    /// qualified and the prize went back to the creator.
+    ///
+    /// # Panics
+    /// * `Error::InvalidStatus` if the giveaway is not `Selecting`
+    /// * `Error::SelectionIncomplete` while batches remain
+    pub fn complete_selection(env: Env, keeper: Address, giveaway_id: u64) -> Option<Address> {
+        keeper.require_auth();
+
+        with_reentrancy_guard(&env, || {
+            let (giveaway, mut state) = Self::load_selection(&env, giveaway_id);
+            if state.processed < state.total {
+                panic_with_error!(&env, Error::SelectionIncomplete);
+            }
+
+            let winners = match giveaway.selection_method {
+                SelectionMethod::Random => state.winners.clone(),
+                _ => Self::leaderboard_addresses(&env, &state.leaderboard),
+            };
+
+            let reward = Self::take_keeper_share(&mut state);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::SelectionState(giveaway_id));
+
+            let giveaway_key = DataKey::Giveaway(giveaway_id);
+            let winner = Self::finalize_winners(&env, &giveaway_key, giveaway.clone(), winners);
+            Self::pay_keeper(&env, &giveaway, &keeper, reward);
+            winner
+        })
+    }
+
+    pub fn get_selection_state(env: Env, giveaway_id: u64) -> Option<SelectionState> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SelectionState(giveaway_id))
+    }
+}
+
+impl GiveawayContract {
+    /// Check that `creator` may edit the giveaway's allowlist now.
+    fn ensure_allowlist_editable(env: &Env, creator: &Address, giveaway_id: u64) {
+        creator.require_auth();
+
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::GiveawayNotFound));
+        extend_content_ttl(env, &giveaway_key);
+
+        if giveaway.creator != *creator {
+            panic_with_error!(env, Error::NotCreator);
+        }
+        if giveaway.status != GiveawayStatus::Active {
+            panic_with_error!(env, Error::InvalidStatus);
+        }
+        if env.ledger().timestamp() > giveaway.end_time {
+            panic_with_error!(env, Error::GiveawayEnded);
+        }
+    }
+
+    /// Load a giveaway in `Selecting` together with its selection progress.
+    fn load_selection(env: &Env, giveaway_id: u64) -> (Giveaway, SelectionState) {
+        let giveaway_key = DataKey::Giveaway(giveaway_id);
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&giveaway_key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::GiveawayNotFound));
+        extend_content_ttl(env, &giveaway_key);
+
+        if giveaway.status != GiveawayStatus::Selecting {
+            panic_with_error!(env, Error::InvalidStatus);
+        }
+        let state: SelectionState = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SelectionState(giveaway_id))
+            .unwrap_or_else(|| panic_with_error!(env, Error::InvalidStatus));
+        (giveaway, state)
+    }
+
+    fn store_selection_state(env: &Env, giveaway_id: u64, state: &SelectionState) {
+        let state_key = DataKey::SelectionState(giveaway_id);
+        env.storage().persistent().set(&state_key, state);
+        extend_persistent(env, &state_key);
+    }
+
+    /// Take the current step's even share of the remaining keeper reward;
+    /// the last step gets whatever is left.
+    fn take_keeper_share(state: &mut SelectionState) -> i128 {
+        let share = state.reward_remaining / state.steps_remaining.max(1) as i128;
+        state.reward_remaining -= share;
+        state.steps_remaining = state.steps_remaining.saturating_sub(1);
+        share
+    }
+
+    fn pay_keeper(env: &Env, giveaway: &Giveaway, keeper: &Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        let token_client = token::Client::new(env, &giveaway.token);
+        token_client.transfer(&env.current_contract_address(), keeper, &amount);
+        decrease_liabilities(env, &giveaway.token, amount);
+
+        KeeperRewarded {
+            keeper: keeper.clone(),
+            giveaway_id: giveaway.id,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Pay out all collected fees in `token` to `to`. Authorization is the
+    /// caller's responsibility: `withdraw_fees` or an executed multisig
+    /// proposal.
+    pub(crate) fn execute_withdraw_fees(env: &Env, token: Address, to: Address) {
+        // 1. Read 'CollectedFees(token)' amount
+        let collected_fees_key = DataKey::CollectedFees(token.clone());
+        let fee_amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&collected_fees_key)
+            .unwrap_or(0);
+
+        // Only proceed if there are fees to withdraw
+        if fee_amount > 0 {
+            // 2. Transfer that amount to `to`
+            let token_client = token::Client::new(env, &token);
+            token_client.transfer(&env.current_contract_address(), &to, &fee_amount);
+            decrease_liabilities(env, &token, fee_amount);
+
+            // 3. Set 'CollectedFees(token)' to 0
+            env.storage().persistent().set(&collected_fees_key, &0i128);
+        }
+    }
 }
@@ -1,4 +1,9 @@
-use crate::types::{ContentType, DataKey, Error, GiveawayStatus, HelpRequestStatus};
+use crate::pause::is_paused;
+use crate::ttl::{extend_content_ttl, extend_persistent};
+use crate::types::{
+    ContentType, DataKey, Disqualification, EntryKey, Error, GiveawayStatus, HelpRequestStatus,
+    PauseModule,
+};
 use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
 
 /// Number of flags required to automatically suspend content.
@@ -33,6 +38,13 @@ pub struct ContentAppealed {
     user: Address,
 }
 
+#[contractevent]
+pub struct DisqualificationAppealed {
+    #[topic]
+    giveaway_id: u64,
+    participant: Address,
+}
+
 #[contractimpl]
 impl GovernanceContract {
     /// Flag a specific Giveaway or HelpRequest by its type and ID.
@@ -46,6 +58,10 @@ impl GovernanceContract {
         // 1. Verify caller signature
         user.require_auth();
 
+        if is_paused(&env, PauseModule::Flagging) {
+            return Err(Error::Paused);
+        }
+
         // 2. Prevent duplicate flags from the same user
         let flag_key = DataKey::FlagRecord(content_type, target_id, user.clone());
         if env.storage().persistent().has(&flag_key) {
@@ -100,6 +116,7 @@ impl GovernanceContract {
             }
             giveaway.status = GiveawayStatus::UnderAppeal;
             env.storage().persistent().set(&giveaway_key, &giveaway);
+            extend_content_ttl(&env, &giveaway_key);
 
             ContentAppealed { target_id, user }.publish(&env);
             return Ok(());
@@ -119,6 +136,7 @@ impl GovernanceContract {
             }
             request.status = HelpRequestStatus::UnderAppeal;
             env.storage().persistent().set(&request_key, &request);
+            extend_content_ttl(&env, &request_key);
 
             ContentAppealed { target_id, user }.publish(&env);
             return Ok(());
@@ -129,6 +147,37 @@ impl GovernanceContract {
         Err(Error::GiveawayNotFound)
     }
 
+    /// Appeal a disqualification from a giveaway. Each disqualification can
+    /// be appealed once; a Moderator decides it with
+    /// `AdminContract::resolve_disqualification_appeal`.
+    pub fn appeal_disqualification(
+        env: Env,
+        participant: Address,
+        giveaway_id: u64,
+    ) -> Result<(), Error> {
+        participant.require_auth();
+
+        let record_key = EntryKey::Disqualification(giveaway_id, participant.clone());
+        let mut record: Disqualification = env
+            .storage()
+            .persistent()
+            .get(&record_key)
+            .ok_or(Error::InvalidStatus)?;
+        if record.appealed {
+            return Err(Error::InvalidStatus);
+        }
+        record.appealed = true;
+        env.storage().persistent().set(&record_key, &record);
+        extend_persistent(&env, &record_key);
+
+        DisqualificationAppealed {
+            giveaway_id,
+            participant,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
     /// Returns the total number of flags for a specific content item.
     pub fn get_flag_count(env: Env, content_type: ContentType, target_id: u64) -> u32 {
         env.storage()
@@ -160,6 +209,7 @@ impl GovernanceContract {
                     if giveaway.status == GiveawayStatus::Active {
                         giveaway.status = GiveawayStatus::Suspended;
                         env.storage().persistent().set(&key, &giveaway);
+                        extend_content_ttl(env, &key);
                         true
                     } else {
                         false
@@ -178,6 +228,7 @@ impl GovernanceContract {
                     if request.status == HelpRequestStatus::Open {
                         request.status = HelpRequestStatus::Suspended;
                         env.storage().persistent().set(&key, &request);
+                        extend_content_ttl(env, &key);
                         true
                     } else {
                         false
@@ -2,11 +2,22 @@
 
 pub mod access;
 pub mod admin;
+pub mod chunks;
+pub mod contest;
+pub mod eligibility;
+pub mod escrow;
 pub mod giveaway;
 pub mod governance;
+pub mod multisig;
 pub mod mutual_aid;
+pub mod participants;
+pub mod pause;
 pub mod profile;
+pub mod timelock;
+pub mod tokens;
+pub mod ttl;
 pub mod types;
+pub mod upgrade;
 pub mod utils;
 
 #[cfg(test)]
@@ -17,6 +28,9 @@ pub use crate::admin::AdminContractClient;
 pub use crate::giveaway::GiveawayContract;
 pub use crate::giveaway::GiveawayContractClient;
 pub use crate::governance::{GovernanceContract, GovernanceContractClient};
+pub use crate::multisig::{MultisigContract, MultisigContractClient};
 pub use crate::mutual_aid::MutualAidContract;
 pub use crate::mutual_aid::MutualAidContractClient;
 pub use crate::profile::{ProfileContract, ProfileContractClient};
+pub use crate::timelock::{TimelockContract, TimelockContractClient};
+pub use crate::upgrade::{UpgradeContract, UpgradeContractClient};
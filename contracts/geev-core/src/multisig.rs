@@ -0,0 +1,268 @@
+use crate::access::check_admin;
+use crate::admin::AdminContract;
+use crate::giveaway::GiveawayContract;
+use crate::timelock::{schedule, timelock_delay};
+use crate::types::{AdminAction, AdminProposal, DataKey, Error, MultisigConfig, TimelockAction};
+use crate::upgrade::UpgradeContract;
+use soroban_sdk::{contract, contractevent, contractimpl, panic_with_error, Address, Env, Vec};
+
+/// How long a multisig proposal can gather approvals and be executed (7 days).
+const PROPOSAL_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+#[contract]
+pub struct MultisigContract;
+
+#[contractevent]
+pub struct MultisigConfigured {
+    signers: Vec<Address>,
+    threshold: u32,
+}
+
+#[contractevent]
+pub struct ProposalSubmitted {
+    #[topic]
+    proposal_id: u64,
+    proposer: Address,
+    action: AdminAction,
+    expires_at: u64,
+}
+
+#[contractevent]
+pub struct ProposalApproved {
+    #[topic]
+    proposal_id: u64,
+    signer: Address,
+    approvals: u32,
+}
+
+#[contractevent]
+pub struct ProposalExecuted {
+    #[topic]
+    proposal_id: u64,
+}
+
+/// Panic with `Error::MultisigRequired` if a multisig has been configured.
+/// Called by the single-key entrypoints of every action in `AdminAction`.
+pub fn ensure_single_key_allowed(env: &Env) {
+    if env.storage().instance().has(&DataKey::MultisigConfig) {
+        panic_with_error!(env, Error::MultisigRequired);
+    }
+}
+
+#[contractimpl]
+impl MultisigContract {
+    /// Install the initial signer set - callable only by Admin, and only
+    /// once. From then on the sensitive actions in `AdminAction` (including
+    /// signer changes) require `threshold` approvals and the single-key
+    /// entrypoints reject them with `Error::MultisigRequired`.
+    ///
+    /// # Panics
+    /// * `Error::AlreadyInitialized` if a multisig is already configured
+    /// * `Error::InvalidThreshold` if the threshold is zero, exceeds the
+    ///   number of signers, or a signer is listed twice
+    pub fn init_multisig(env: Env, signers: Vec<Address>, threshold: u32) {
+        check_admin(&env);
+
+        if env.storage().instance().has(&DataKey::MultisigConfig) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        Self::store_config(&env, signers, threshold);
+    }
+
+    pub fn get_multisig_config(env: Env) -> Option<MultisigConfig> {
+        env.storage().instance().get(&DataKey::MultisigConfig)
+    }
+
+    /// Submit a sensitive admin action for approval. The submitting signer's
+    /// approval is counted immediately.
+    pub fn submit_proposal(env: Env, signer: Address, action: AdminAction) -> u64 {
+        signer.require_auth();
+
+        let config = Self::load_config(&env);
+        if !config.signers.contains(&signer) {
+            panic_with_error!(&env, Error::NotSigner);
+        }
+
+        let mut proposal_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminProposalCounter)
+            .unwrap_or(0);
+        proposal_id += 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminProposalCounter, &proposal_id);
+
+        let expires_at = env.ledger().timestamp() + PROPOSAL_TTL_SECONDS;
+        let proposal = AdminProposal {
+            id: proposal_id,
+            action: action.clone(),
+            proposer: signer.clone(),
+            approvals: Vec::from_array(&env, [signer.clone()]),
+            expires_at,
+            executed: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::AdminProposal(proposal_id), &proposal);
+
+        ProposalSubmitted {
+            proposal_id,
+            proposer: signer,
+            action,
+            expires_at,
+        }
+        .publish(&env);
+
+        proposal_id
+    }
+
+    /// Add `signer`'s approval to a pending proposal.
+    ///
+    /// # Panics
+    /// * `Error::NotSigner` if `signer` is not in the current signer set
+    /// * `Error::AlreadyApproved` if `signer` already approved
+    /// * `Error::ProposalExpired` / `Error::InvalidStatus` if the proposal
+    ///   lapsed or was already executed
+    pub fn approve_proposal(env: Env, signer: Address, proposal_id: u64) {
+        signer.require_auth();
+
+        let config = Self::load_config(&env);
+        if !config.signers.contains(&signer) {
+            panic_with_error!(&env, Error::NotSigner);
+        }
+
+        let mut proposal = Self::load_pending(&env, proposal_id);
+        if proposal.approvals.contains(&signer) {
+            panic_with_error!(&env, Error::AlreadyApproved);
+        }
+        proposal.approvals.push_back(signer.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::AdminProposal(proposal_id), &proposal);
+
+        ProposalApproved {
+            proposal_id,
+            signer,
+            approvals: proposal.approvals.len(),
+        }
+        .publish(&env);
+    }
+
+    /// Run an approved proposal. Anyone may trigger execution once enough
+    /// current signers have approved; approvals from signers removed since
+    /// do not count.
+    ///
+    /// # Panics
+    /// Panics with `Error::ThresholdNotMet` if approvals are still missing
+    pub fn execute_proposal(env: Env, proposal_id: u64) {
+        let config = Self::load_config(&env);
+        let mut proposal = Self::load_pending(&env, proposal_id);
+
+        let mut valid_approvals = 0u32;
+        for approver in proposal.approvals.iter() {
+            if config.signers.contains(&approver) {
+                valid_approvals += 1;
+            }
+        }
+        if valid_approvals < config.threshold {
+            panic_with_error!(&env, Error::ThresholdNotMet);
+        }
+
+        // Mark executed before running the action so it cannot be replayed.
+        proposal.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AdminProposal(proposal_id), &proposal);
+
+        match proposal.action {
+            // Timelocked actions are queued rather than run while a delay
+            // is set; `TimelockContract::execute_action` finishes them.
+            AdminAction::Withdraw(token, amount, to) => {
+                if timelock_delay(&env) > 0 {
+                    schedule(&env, TimelockAction::Withdraw(token, amount, to));
+                } else {
+                    AdminContract::execute_withdraw(&env, token, amount, to);
+                }
+            }
+            AdminAction::ProposeAdmin(new_admin) => {
+                if timelock_delay(&env) > 0 {
+                    schedule(&env, TimelockAction::ProposeAdmin(new_admin));
+                } else {
+                    AdminContract::execute_propose_admin(&env, new_admin);
+                }
+            }
+            AdminAction::Upgrade(new_wasm_hash) => {
+                if timelock_delay(&env) > 0 {
+                    schedule(&env, TimelockAction::Upgrade(new_wasm_hash));
+                } else {
+                    UpgradeContract::execute_upgrade(&env, new_wasm_hash);
+                }
+            }
+            AdminAction::AddToken(token) => {
+                AdminContract::execute_add_token(&env, token);
+            }
+            AdminAction::WithdrawFees(token, to) => {
+                GiveawayContract::execute_withdraw_fees(&env, token, to);
+            }
+            AdminAction::UpdateSigners(signers, threshold) => {
+                Self::store_config(&env, signers, threshold);
+            }
+        }
+
+        ProposalExecuted { proposal_id }.publish(&env);
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<AdminProposal> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AdminProposal(proposal_id))
+    }
+}
+
+impl MultisigContract {
+    fn load_config(env: &Env) -> MultisigConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::MultisigConfig)
+            .unwrap_or_else(|| panic_with_error!(env, Error::NotSigner))
+    }
+
+    /// Load a proposal that can still be approved or executed.
+    fn load_pending(env: &Env, proposal_id: u64) -> AdminProposal {
+        let proposal: AdminProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AdminProposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(env, Error::ProposalNotFound));
+        if proposal.executed {
+            panic_with_error!(env, Error::InvalidStatus);
+        }
+        if env.ledger().timestamp() > proposal.expires_at {
+            panic_with_error!(env, Error::ProposalExpired);
+        }
+        proposal
+    }
+
+    fn store_config(env: &Env, signers: Vec<Address>, threshold: u32) {
+        if threshold == 0 || threshold > signers.len() {
+            panic_with_error!(env, Error::InvalidThreshold);
+        }
+        for (index, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(index as u32) {
+                panic_with_error!(env, Error::InvalidThreshold);
+            }
+        }
+
+        env.storage().instance().set(
+            &DataKey::MultisigConfig,
+            &MultisigConfig {
+                signers: signers.clone(),
+                threshold,
+            },
+        );
+
+        MultisigConfigured { signers, threshold }.publish(env);
+    }
+}
@@ -1,9 +1,23 @@
-use crate::types::{DataKey, Error, HelpRequest, HelpRequestStatus};
+use crate::chunks::ChunkedList;
+use crate::escrow::{decrease_liabilities, increase_liabilities};
+use crate::pause::ensure_not_paused;
+use crate::ttl::{extend_content_ttl, extend_persistent, PRUNE_BATCH_SIZE};
+use crate::types::{
+    DataKey, Error, HelpRequest, HelpRequestStatus, HelpRequestSummary, PauseModule,
+};
 use crate::utils::with_reentrancy_guard;
 use soroban_sdk::{contract, contractevent, contractimpl, panic_with_error, token, Address, Env};
 
 const HELP_REQUEST_EXPIRY_SECONDS: u64 = 30 * 24 * 60 * 60;
 
+/// The addresses holding a `Donation` record on `request_id`, in the order
+/// they first donated.
+pub fn donors(request_id: u64) -> ChunkedList<DataKey, impl Fn(u32) -> DataKey> {
+    ChunkedList::new(DataKey::DonorCount(request_id), move |chunk| {
+        DataKey::DonorChunk(request_id, chunk)
+    })
+}
+
 #[contract]
 pub struct MutualAidContract;
 
@@ -32,6 +46,12 @@ pub struct RefundClaimed {
     amount: i128,
 }
 
+#[contractevent]
+pub struct HelpRequestPruned {
+    #[topic]
+    request_id: u64,
+}
+
 #[contractevent]
 pub struct RequestCancelled {
     request_id: u64,
@@ -55,6 +75,94 @@ impl MutualAidContract {
         env.storage().persistent().get(&request_key)
     }
 
+    /// Extend the TTL of a help request and every entry belonging to it:
+    /// donation records and the payout claim record. Callable by anyone, so
+    /// donors can keep a long-running request from being archived.
+    ///
+    /// Donation records are extended `max_chunks` donor chunks at a time,
+    /// from chunk `start_chunk`; the claim record by the call with
+    /// `start_chunk` 0. Returns `true` once no chunk is left after this
+    /// call's.
+    pub fn bump_request(env: Env, request_id: u64, start_chunk: u32, max_chunks: u32) -> bool {
+        let request_key = DataKey::HelpRequest(request_id);
+        if !env.storage().persistent().has(&request_key) {
+            panic_with_error!(&env, Error::HelpRequestNotFound);
+        }
+        extend_content_ttl(&env, &request_key);
+
+        if start_chunk == 0 {
+            extend_persistent(&env, &DataKey::HelpRequestClaimed(request_id));
+        }
+        donors(request_id).extend(&env, start_chunk, max_chunks, |donor| {
+            extend_persistent(&env, &DataKey::Donation(request_id, donor));
+        })
+    }
+
+    /// Delete the donation records of a `Closed` help request. Callable by
+    /// anyone.
+    ///
+    /// Deletes at most `PRUNE_BATCH_SIZE` entries per call and returns `true`
+    /// once all are gone. The first call archives a `HelpRequestSummary`,
+    /// which also keeps the id from being reused; the last deletes the
+    /// `HelpRequest` itself.
+    ///
+    /// # Panics
+    /// * `Error::HelpRequestNotFound` if the request does not exist or has
+    ///   already been pruned
+    /// * `Error::InvalidStatus` unless the request is `Closed`
+    pub fn prune_request(env: Env, request_id: u64) -> bool {
+        let request_key = DataKey::HelpRequest(request_id);
+        let request: HelpRequest = env
+            .storage()
+            .persistent()
+            .get(&request_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::HelpRequestNotFound));
+
+        if request.status != HelpRequestStatus::Closed {
+            panic_with_error!(&env, Error::InvalidStatus);
+        }
+
+        let summary_key = DataKey::HelpRequestSummary(request_id);
+        if !env.storage().persistent().has(&summary_key) {
+            let summary = HelpRequestSummary {
+                id: request_id,
+                creator: request.creator.clone(),
+                token: request.token.clone(),
+                goal: request.goal,
+                raised_amount: request.raised_amount,
+                donor_count: donors(request_id).len(&env),
+                created_at: request.created_at,
+                pruned_at: env.ledger().timestamp(),
+            };
+            env.storage().persistent().set(&summary_key, &summary);
+            extend_persistent(&env, &summary_key);
+        }
+
+        let removed = donors(request_id).pop_up_to(&env, PRUNE_BATCH_SIZE);
+        for donor in removed.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Donation(request_id, donor));
+        }
+        if removed.len() == PRUNE_BATCH_SIZE {
+            return false;
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::HelpRequestClaimed(request_id));
+        env.storage().persistent().remove(&request_key);
+
+        HelpRequestPruned { request_id }.publish(&env);
+        true
+    }
+
+    pub fn get_request_summary(env: Env, request_id: u64) -> Option<HelpRequestSummary> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::HelpRequestSummary(request_id))
+    }
+
     pub fn post_help_request(
         env: Env,
         creator: Address,
@@ -68,9 +176,14 @@ impl MutualAidContract {
             panic_with_error!(&env, Error::InvalidGoalAmount);
         }
 
-        // Prevent overwriting an existing request
+        // Prevent overwriting an existing request, live or pruned
         let request_key = DataKey::HelpRequest(request_id);
-        if env.storage().persistent().has(&request_key) {
+        if env.storage().persistent().has(&request_key)
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::HelpRequestSummary(request_id))
+        {
             panic_with_error!(&env, Error::HelpRequestAlreadyExists);
         }
 
@@ -88,6 +201,7 @@ impl MutualAidContract {
         };
 
         env.storage().persistent().set(&request_key, &request);
+        extend_content_ttl(&env, &request_key);
 
         HelpRequestPosted {
             request_id,
@@ -101,6 +215,7 @@ impl MutualAidContract {
 
     pub fn donate(env: Env, donor: Address, request_id: u64, amount: i128) {
         donor.require_auth();
+        ensure_not_paused(&env, PauseModule::Donations);
 
         if amount <= 0 {
             panic_with_error!(&env, Error::InvalidDonationAmount);
@@ -112,6 +227,7 @@ impl MutualAidContract {
             .persistent()
             .get(&request_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::HelpRequestNotFound));
+        extend_content_ttl(&env, &request_key);
 
         if request.status == HelpRequestStatus::FullyFunded {
             panic_with_error!(&env, Error::HelpRequestAlreadyFullyFunded);
@@ -135,13 +251,19 @@ impl MutualAidContract {
         let token_client = token::Client::new(&env, &request.token);
 
         token_client.transfer(&donor, env.current_contract_address(), &amount);
+        increase_liabilities(&env, &request.token, amount);
 
         let donation_key = DataKey::Donation(request_id, donor.clone());
-        let previous_donation: i128 = env.storage().persistent().get(&donation_key).unwrap_or(0);
+        let previous_donation: Option<i128> = env.storage().persistent().get(&donation_key);
+        if previous_donation.is_none() {
+            donors(request_id).push(&env, &donor);
+        }
         let new_donation = previous_donation
+            .unwrap_or(0)
             .checked_add(amount)
             .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
         env.storage().persistent().set(&donation_key, &new_donation);
+        extend_persistent(&env, &donation_key);
 
         let new_raised = request
             .raised_amount
@@ -167,6 +289,7 @@ impl MutualAidContract {
 
     pub fn claim_refund(env: Env, donor: Address, request_id: u64) {
         donor.require_auth();
+        ensure_not_paused(&env, PauseModule::Refunds);
 
         let request_key = DataKey::HelpRequest(request_id);
         let request: HelpRequest = env
@@ -174,6 +297,7 @@ impl MutualAidContract {
             .persistent()
             .get(&request_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::HelpRequestNotFound));
+        extend_content_ttl(&env, &request_key);
 
         if request.status != HelpRequestStatus::Cancelled {
             panic_with_error!(&env, Error::InvalidStatus);
@@ -188,6 +312,7 @@ impl MutualAidContract {
 
         let token_client = token::Client::new(&env, &request.token);
         token_client.transfer(&env.current_contract_address(), &donor, &amount);
+        decrease_liabilities(&env, &request.token, amount);
 
         // Reset donation amount to prevent double refund
         env.storage().persistent().set(&donation_key, &0i128);
@@ -211,6 +336,7 @@ impl MutualAidContract {
     /// payout cannot be repeated.
     pub fn claim_help_request_funds(env: Env, creator: Address, request_id: u64) {
         creator.require_auth();
+        ensure_not_paused(&env, PauseModule::Claims);
 
         with_reentrancy_guard(&env, || {
             let request_key = DataKey::HelpRequest(request_id);
@@ -219,6 +345,7 @@ impl MutualAidContract {
                 .persistent()
                 .get(&request_key)
                 .unwrap_or_else(|| panic_with_error!(&env, Error::HelpRequestNotFound));
+            extend_content_ttl(&env, &request_key);
 
             if request.creator != creator {
                 panic_with_error!(&env, Error::NotCreator);
@@ -247,8 +374,10 @@ impl MutualAidContract {
 
             let token_client = token::Client::new(&env, &request.token);
             token_client.transfer(&env.current_contract_address(), &creator, &amount);
+            decrease_liabilities(&env, &request.token, amount);
 
             env.storage().persistent().set(&claimed_key, &true);
+            extend_persistent(&env, &claimed_key);
 
             request.status = HelpRequestStatus::Closed;
             env.storage().persistent().set(&request_key, &request);
@@ -271,6 +400,7 @@ impl MutualAidContract {
             .persistent()
             .get(&request_key)
             .unwrap_or_else(|| panic_with_error!(&env, Error::HelpRequestNotFound));
+        extend_content_ttl(&env, &request_key);
 
         if request.creator != creator {
             panic_with_error!(&env, Error::NotCreator);
@@ -296,3 +426,16 @@ impl MutualAidContract {
         .publish(&env);
     }
 }
+
+impl MutualAidContract {
+    /// How much of `request`'s donations the contract still holds. Used by
+    /// `migrate` to seed `Liabilities`. Refunds from a `Cancelled` request
+    /// leave no running total, so its whole `raised_amount` is counted:
+    /// liabilities may then err high, which only keeps surplus locked.
+    pub(crate) fn unpaid_escrow(request: &HelpRequest) -> i128 {
+        match request.status {
+            HelpRequestStatus::Closed => 0,
+            _ => request.raised_amount,
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use crate::ttl::extend_persistent;
+use crate::types::{DataKey, Disqualification, EntryKey};
+use soroban_sdk::{Address, Env, Vec};
+
+/// Participants stored per `ParticipantChunk` entry. An entry appends one
+/// address to an existing chunk instead of creating a new ledger entry, so
+/// rent is only paid for the added bytes; every 64th entry opens a new chunk.
+pub const PARTICIPANT_CHUNK_SIZE: u32 = 64;
+
+/// Number of participants who have entered `giveaway_id`.
+pub fn participant_count(env: &Env, giveaway_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ParticipantCount(giveaway_id))
+        .unwrap_or(0)
+}
+
+/// The participants at entry indexes `chunk * PARTICIPANT_CHUNK_SIZE` onwards.
+pub fn participant_chunk(env: &Env, giveaway_id: u64, chunk: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ParticipantChunk(giveaway_id, chunk))
+        .unwrap_or(Vec::new(env))
+}
+
+/// The participant at entry position `index`, if there is one.
+pub fn participant_at(env: &Env, giveaway_id: u64, index: u32) -> Option<Address> {
+    participant_chunk(env, giveaway_id, index / PARTICIPANT_CHUNK_SIZE)
+        .get(index % PARTICIPANT_CHUNK_SIZE)
+}
+
+/// Append `participant` to the giveaway's entry order and return the index
+/// it was stored at. Membership (`HasEntered`) is the caller's concern.
+pub fn add_participant(env: &Env, giveaway_id: u64, participant: &Address) -> u32 {
+    let index = participant_count(env, giveaway_id);
+
+    let chunk_key = DataKey::ParticipantChunk(giveaway_id, index / PARTICIPANT_CHUNK_SIZE);
+    let mut chunk: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&chunk_key)
+        .unwrap_or(Vec::new(env));
+    chunk.push_back(participant.clone());
+    env.storage().persistent().set(&chunk_key, &chunk);
+    extend_persistent(env, &chunk_key);
+
+    let count_key = DataKey::ParticipantCount(giveaway_id);
+    env.storage().persistent().set(&count_key, &(index + 1));
+    extend_persistent(env, &count_key);
+
+    index
+}
+
+/// Whether `participant` holds a live entry in `giveaway_id`. Entrants who
+/// left keep their slot, with `HasEntered` cleared to `false`.
+pub fn has_entered(env: &Env, giveaway_id: u64, participant: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HasEntered(giveaway_id, participant.clone()))
+        .unwrap_or(false)
+}
+
+/// Number of entrants who left `giveaway_id` with `leave_giveaway`.
+pub fn withdrawn_count(env: &Env, giveaway_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&EntryKey::WithdrawnCount(giveaway_id))
+        .unwrap_or(0)
+}
+
+/// Entrants still in the giveaway: every slot, less those left empty.
+pub fn entrant_count(env: &Env, giveaway_id: u64) -> u32 {
+    participant_count(env, giveaway_id) - withdrawn_count(env, giveaway_id)
+}
+
+/// Empty `participant`'s slot. It stays in the chunk, so entry indexes do
+/// not shift; selection skips it until `rejoin_participant` fills it again.
+pub fn withdraw_participant(env: &Env, giveaway_id: u64, participant: &Address) {
+    set_entered(env, giveaway_id, participant, false);
+    set_withdrawn_count(env, giveaway_id, withdrawn_count(env, giveaway_id) + 1);
+}
+
+/// Give `participant`, who left, their emptied slot back.
+pub fn rejoin_participant(env: &Env, giveaway_id: u64, participant: &Address) {
+    set_entered(env, giveaway_id, participant, true);
+    set_withdrawn_count(env, giveaway_id, withdrawn_count(env, giveaway_id) - 1);
+}
+
+fn set_entered(env: &Env, giveaway_id: u64, participant: &Address, entered: bool) {
+    let has_entered_key = DataKey::HasEntered(giveaway_id, participant.clone());
+    env.storage().persistent().set(&has_entered_key, &entered);
+    extend_persistent(env, &has_entered_key);
+}
+
+fn set_withdrawn_count(env: &Env, giveaway_id: u64, count: u32) {
+    let count_key = EntryKey::WithdrawnCount(giveaway_id);
+    env.storage().persistent().set(&count_key, &count);
+    extend_persistent(env, &count_key);
+}
+
+/// The entries a giveaway keeps per participant besides `HasEntered`.
+#[derive(Clone, Copy)]
+pub struct EntrantEntries {
+    /// Skill-contest `AnswerCommitment`s, until revealed.
+    pub answers: bool,
+    /// `AdmittedBy` records of a `hold_until_draw` giveaway.
+    pub admissions: bool,
+    /// `EntryIndex` records of a merit giveaway.
+    pub indexes: bool,
+}
+
+impl EntrantEntries {
+    fn per_participant(self) -> u32 {
+        1 + self.answers as u32 + self.admissions as u32 + self.indexes as u32
+    }
+
+    fn keys(self, env: &Env, giveaway_id: u64, participant: &Address) -> Vec<EntryKey> {
+        let mut keys = Vec::new(env);
+        if self.answers {
+            keys.push_back(EntryKey::AnswerCommitment(giveaway_id, participant.clone()));
+        }
+        if self.admissions {
+            keys.push_back(EntryKey::AdmittedBy(giveaway_id, participant.clone()));
+        }
+        if self.indexes {
+            keys.push_back(EntryKey::EntryIndex(giveaway_id, participant.clone()));
+        }
+        keys
+    }
+}
+
+/// Extend the participant entries of a giveaway in chunks `start_chunk` up
+/// to `start_chunk + max_chunks`: the chunks themselves and each
+/// participant's `HasEntered` flag and `entries`. From chunk 0 on, the
+/// counts and any disqualifications are extended too. Returns `true` if no
+/// chunk is left after these.
+pub fn extend_participants(
+    env: &Env,
+    giveaway_id: u64,
+    entries: EntrantEntries,
+    start_chunk: u32,
+    max_chunks: u32,
+) -> bool {
+    let chunk_count = participant_count(env, giveaway_id).div_ceil(PARTICIPANT_CHUNK_SIZE);
+    if start_chunk == 0 {
+        extend_persistent(env, &DataKey::ParticipantCount(giveaway_id));
+        extend_persistent(env, &EntryKey::WithdrawnCount(giveaway_id));
+
+        extend_persistent(env, &EntryKey::DisqualifiedMembers(giveaway_id));
+        for participant in disqualified_participants(env, giveaway_id).iter() {
+            extend_persistent(env, &EntryKey::Disqualification(giveaway_id, participant));
+        }
+    }
+
+    let end_chunk = start_chunk.saturating_add(max_chunks);
+    for chunk in start_chunk..end_chunk.min(chunk_count) {
+        let chunk_key = DataKey::ParticipantChunk(giveaway_id, chunk);
+        extend_persistent(env, &chunk_key);
+        for participant in participant_chunk(env, giveaway_id, chunk).iter() {
+            for key in entries.keys(env, giveaway_id, &participant).iter() {
+                extend_persistent(env, &key);
+            }
+            extend_persistent(env, &DataKey::HasEntered(giveaway_id, participant));
+        }
+    }
+    end_chunk >= chunk_count
+}
+
+/// Delete up to `limit` entries of a giveaway's participants, latest
+/// entrant first: their `HasEntered` flags and `entries`, so each
+/// participant takes one to three of `limit`. The withdrawn count goes with
+/// the last of them. Returns how many entries were deleted.
+pub fn remove_participants(
+    env: &Env,
+    giveaway_id: u64,
+    limit: u32,
+    entries: EntrantEntries,
+) -> u32 {
+    let per_participant = entries.per_participant();
+    let mut count = participant_count(env, giveaway_id);
+    let mut removed = 0;
+    while count > 0 && removed + per_participant <= limit {
+        let chunk_index = (count - 1) / PARTICIPANT_CHUNK_SIZE;
+        let chunk_key = DataKey::ParticipantChunk(giveaway_id, chunk_index);
+        let mut chunk = participant_chunk(env, giveaway_id, chunk_index);
+        while removed + per_participant <= limit {
+            let Some(participant) = chunk.pop_back() else {
+                break;
+            };
+            for key in entries.keys(env, giveaway_id, &participant).iter() {
+                env.storage().persistent().remove(&key);
+            }
+            env.storage()
+                .persistent()
+                .remove(&DataKey::HasEntered(giveaway_id, participant));
+            removed += per_participant;
+        }
+        count = chunk_index * PARTICIPANT_CHUNK_SIZE + chunk.len();
+        if chunk.is_empty() {
+            env.storage().persistent().remove(&chunk_key);
+        } else {
+            env.storage().persistent().set(&chunk_key, &chunk);
+        }
+    }
+
+    let count_key = DataKey::ParticipantCount(giveaway_id);
+    if count == 0 {
+        env.storage().persistent().remove(&count_key);
+        env.storage()
+            .persistent()
+            .remove(&EntryKey::WithdrawnCount(giveaway_id));
+    } else {
+        env.storage().persistent().set(&count_key, &count);
+    }
+    removed
+}
+
+/// The entrants disqualified from `giveaway_id`, in the order it happened.
+pub fn disqualified_participants(env: &Env, giveaway_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&EntryKey::DisqualifiedMembers(giveaway_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn is_disqualified(env: &Env, giveaway_id: u64, participant: &Address) -> bool {
+    env.storage().persistent().has(&EntryKey::Disqualification(
+        giveaway_id,
+        participant.clone(),
+    ))
+}
+
+/// Entrants who can still be drawn: everyone still entered, less the
+/// disqualified.
+pub fn eligible_count(env: &Env, giveaway_id: u64) -> u32 {
+    entrant_count(env, giveaway_id) - disqualified_participants(env, giveaway_id).len()
+}
+
+/// Record `participant`'s disqualification. Their entry stays in place, so
+/// entry indexes do not shift; selection skips them.
+pub fn add_disqualification(env: &Env, giveaway_id: u64, participant: &Address, reason_code: u32) {
+    let record_key = EntryKey::Disqualification(giveaway_id, participant.clone());
+    let record = Disqualification {
+        reason_code,
+        appealed: false,
+    };
+    env.storage().persistent().set(&record_key, &record);
+    extend_persistent(env, &record_key);
+
+    let mut members = disqualified_participants(env, giveaway_id);
+    members.push_back(participant.clone());
+    store_disqualified(env, giveaway_id, &members);
+}
+
+/// Lift `participant`'s disqualification, making them drawable again.
+pub fn remove_disqualification(env: &Env, giveaway_id: u64, participant: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&EntryKey::Disqualification(
+            giveaway_id,
+            participant.clone(),
+        ));
+
+    let mut members = disqualified_participants(env, giveaway_id);
+    if let Some(index) = members.first_index_of(participant) {
+        members.remove(index);
+    }
+    store_disqualified(env, giveaway_id, &members);
+}
+
+/// Delete up to `limit` disqualification records of a giveaway. Returns
+/// how many were deleted; fewer than `limit` means none are left.
+pub fn remove_disqualifications(env: &Env, giveaway_id: u64, limit: u32) -> u32 {
+    let mut members = disqualified_participants(env, giveaway_id);
+    let mut removed = 0;
+    while removed < limit {
+        let Some(participant) = members.pop_back() else {
+            break;
+        };
+        env.storage()
+            .persistent()
+            .remove(&EntryKey::Disqualification(giveaway_id, participant));
+        removed += 1;
+    }
+    store_disqualified(env, giveaway_id, &members);
+    removed
+}
+
+fn store_disqualified(env: &Env, giveaway_id: u64, members: &Vec<Address>) {
+    let members_key = EntryKey::DisqualifiedMembers(giveaway_id);
+    if members.is_empty() {
+        env.storage().persistent().remove(&members_key);
+    } else {
+        env.storage().persistent().set(&members_key, members);
+        extend_persistent(env, &members_key);
+    }
+}
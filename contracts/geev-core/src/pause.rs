@@ -0,0 +1,41 @@
+use crate::types::{DataKey, Error, PauseMode, PauseModule};
+use soroban_sdk::{panic_with_error, Env};
+
+/// Read the global pause level (defaults to `Unpaused`).
+pub fn get_pause_mode(env: &Env) -> PauseMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::PauseMode)
+        .unwrap_or(PauseMode::Unpaused)
+}
+
+/// Read whether `module` has been paused on its own.
+pub fn is_module_paused(env: &Env, module: PauseModule) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::ModulePaused(module))
+        .unwrap_or(false)
+}
+
+/// Whether an entrypoint in `module` may run right now.
+///
+/// A module's own flag always blocks it. The global `WithdrawOnly` level
+/// blocks every module except the exits (claims and refunds), so users are
+/// never trapped; `Full` blocks everything.
+pub fn is_paused(env: &Env, module: PauseModule) -> bool {
+    if is_module_paused(env, module) {
+        return true;
+    }
+    match get_pause_mode(env) {
+        PauseMode::Unpaused => false,
+        PauseMode::WithdrawOnly => !matches!(module, PauseModule::Claims | PauseModule::Refunds),
+        PauseMode::Full => true,
+    }
+}
+
+/// Panic with `Error::Paused` if `module` is paused.
+pub fn ensure_not_paused(env: &Env, module: PauseModule) {
+    if is_paused(env, module) {
+        panic_with_error!(env, Error::Paused);
+    }
+}
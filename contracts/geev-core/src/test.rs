@@ -2,15 +2,20 @@ use crate::access::check_admin;
 use crate::admin::{AdminContract, AdminContractClient};
 use crate::giveaway::{GiveawayContract, GiveawayContractClient};
 use crate::governance::{GovernanceContract, GovernanceContractClient};
+use crate::multisig::{MultisigContract, MultisigContractClient};
 use crate::mutual_aid::{MutualAidContract, MutualAidContractClient};
 use crate::profile::{ProfileContract, ProfileContractClient};
+use crate::timelock::{TimelockContract, TimelockContractClient};
 use crate::types::{
-    DataKey, Error, Giveaway, HelpRequest, HelpRequestStatus, ParticipantVerification,
+    DataKey, EligibilityRule, Error, Giveaway, HelpRequest, HelpRequestStatus,
+    ParticipantVerification,
 };
+use crate::upgrade::{UpgradeContract, UpgradeContractClient, CURRENT_SCHEMA_VERSION};
 use soroban_sdk::symbol_short;
 use soroban_sdk::{
+    contracttype,
     testutils::{Address as _, Events as _, Ledger},
-    token, vec, Address, Env, FromVal, IntoVal, String, Symbol, Val, Vec,
+    token, vec, Address, BytesN, Env, FromVal, IntoVal, String, Symbol, Val, Vec,
 };
 
 #[test]
@@ -38,7 +43,7 @@ fn test_giveaway_flow() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -67,7 +72,7 @@ fn test_giveaway_flow() {
         li.timestamp += 100;
     });
 
-    let winner = contract_client.pick_winner(&target_giveaway_id);
+    let winner = contract_client.pick_winner(&target_giveaway_id).unwrap();
 
     assert!(winner == user1 || winner == user2);
 
@@ -99,7 +104,7 @@ fn test_creator_cancels_empty_giveaway_and_recovers_escrow() {
     token::StellarAssetClient::new(&env, &token).mint(&creator, &500);
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(token.clone()), &true);
     });
 
@@ -157,7 +162,7 @@ fn test_giveaway_cancellation_rejects_non_creator_and_existing_entries() {
     token::StellarAssetClient::new(&env, &token).mint(&creator, &500);
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(token.clone()), &true);
     });
 
@@ -211,7 +216,7 @@ fn test_allowlist_rejects_unverified_participant() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -227,8 +232,10 @@ fn test_allowlist_rejects_unverified_participant() {
         &1,
         &Some(ParticipantVerification {
             allowlist,
-            min_reputation: 0,
-            uses_reputation: false,
+            rule: EligibilityRule::Allowlisted,
+            merkle_root: None,
+            permit_verifier: None,
+            hold_until_draw: false,
         }),
     );
 
@@ -259,7 +266,7 @@ fn test_reputation_gated_giveaway_rejects_low_reputation() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
         env.storage()
             .persistent()
@@ -275,8 +282,10 @@ fn test_reputation_gated_giveaway_rejects_low_reputation() {
         &1,
         &Some(ParticipantVerification {
             allowlist: Vec::new(&env),
-            min_reputation: 5,
-            uses_reputation: true,
+            rule: EligibilityRule::MinReputation(5),
+            merkle_root: None,
+            permit_verifier: None,
+            hold_until_draw: false,
         }),
     );
 
@@ -307,7 +316,7 @@ fn test_multi_winner_giveaway_selects_unique_winners() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -327,7 +336,7 @@ fn test_multi_winner_giveaway_selects_unique_winners() {
 
     env.ledger().with_mut(|li| li.timestamp += 100);
 
-    let winner = contract_client.pick_winner(&giveaway_id);
+    let winner = contract_client.pick_winner(&giveaway_id).unwrap();
     assert!(winner == participant1 || winner == participant2 || winner == participant3);
 
     let winners: Vec<Address> = env.as_contract(&contract_id, || {
@@ -379,7 +388,7 @@ fn test_double_entry_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -422,7 +431,7 @@ fn test_enter_late_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -467,7 +476,7 @@ fn test_pick_winner_early_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -853,7 +862,7 @@ fn test_claim_prize() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -873,7 +882,7 @@ fn test_claim_prize() {
         li.timestamp += 100;
     });
 
-    let picked_winner = contract_client.pick_winner(&giveaway_id);
+    let picked_winner = contract_client.pick_winner(&giveaway_id).unwrap();
     assert_eq!(picked_winner, winner);
 
     assert_eq!(token_client.balance(&winner), 0);
@@ -930,7 +939,7 @@ fn test_claim_prize_wrong_status_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -1154,7 +1163,7 @@ fn test_claim_prize_reentrancy_protection() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -1209,7 +1218,7 @@ fn test_add_token_to_whitelist() {
     env.as_contract(&contract_id, || {
         let is_allowed: bool = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::AllowedToken(token.clone()))
             .unwrap_or(false);
         assert!(is_allowed);
@@ -1257,7 +1266,7 @@ fn test_create_giveaway_with_whitelisted_token() {
 
     env.as_contract(&giveaway_contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -1454,7 +1463,7 @@ fn test_withdraw_fees() {
 
     env.as_contract(&giveaway_contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -1496,7 +1505,7 @@ fn test_withdraw_fees() {
     });
 
     // Withdraw fees using giveaway contract
-    giveaway_client.withdraw_fees(&mock_token);
+    giveaway_client.withdraw_fees(&admin, &mock_token);
 
     // Verify fees were transferred to admin and counter reset
     assert_eq!(token_client.balance(&admin), 5);
@@ -1524,7 +1533,7 @@ fn test_withdraw_fees_fails_non_admin() {
     let token = Address::generate(&env);
 
     // DO NOT initialize admin - should panic
-    giveaway_client.withdraw_fees(&token);
+    giveaway_client.withdraw_fees(&Address::generate(&env), &token);
 }
 
 #[test]
@@ -1565,7 +1574,7 @@ fn test_toggle_request_verification() {
     });
 
     // Toggle to verified
-    contract_client.toggle_request_verification(&request_id);
+    contract_client.toggle_request_verification(&admin, &request_id);
 
     env.as_contract(&contract_id, || {
         let request: HelpRequest = env
@@ -1577,7 +1586,7 @@ fn test_toggle_request_verification() {
     });
 
     // Toggle back to unverified
-    contract_client.toggle_request_verification(&request_id);
+    contract_client.toggle_request_verification(&admin, &request_id);
 
     env.as_contract(&contract_id, || {
         let request: HelpRequest = env
@@ -1599,7 +1608,7 @@ fn test_toggle_request_verification_fails_non_admin() {
     let contract_client = AdminContractClient::new(&env, &contract_id);
 
     // DO NOT initialize admin - should panic
-    contract_client.toggle_request_verification(&1u64);
+    contract_client.toggle_request_verification(&Address::generate(&env), &1u64);
 }
 
 /// Verifies that `donate` emits a `DonationReceived` event whose data contains
@@ -1797,7 +1806,10 @@ fn test_flags_are_independent_for_content_types_with_same_id() {
 // ── auto-suspension tests ─────────────────────────────────────────────────────
 
 use crate::governance::FLAG_THRESHOLD;
-use crate::types::{ContentType, GiveawayStatus, SelectionMethod};
+use crate::types::{
+    AdminAction, ContentType, FeeMode, FeeTier, GiveawayStatus, MeritEntry, MultisigConfig,
+    PauseMode, PauseModule, Role, SelectionMethod, Solvency, TimelockAction, TimelockStatus,
+};
 
 /// Seed a minimal active Giveaway directly into contract storage.
 fn seed_active_giveaway(env: &Env, contract_id: &Address, giveaway_id: u64, token: &Address) {
@@ -1808,16 +1820,20 @@ fn seed_active_giveaway(env: &Env, contract_id: &Address, giveaway_id: u64, toke
         token: token.clone(),
         amount: 500,
         title: String::from_str(env, "Test"),
-        participant_count: 0,
+        start_time: 0,
         end_time: env.ledger().timestamp() + 3600,
+        max_participants: None,
+        min_participants: 0,
         status: GiveawayStatus::Active,
         winner_count: 1,
         winners: Vec::new(env),
-        verification_type: 0,
-        min_reputation: 0,
+        eligibility: Vec::new(env),
+        hold_until_draw: false,
         selection_method: SelectionMethod::Random,
         claim_deadline: 0,
         claimed_count: 0,
+        fee_mode: FeeMode::WinnerPays,
+        fee_bps: 100,
     };
     env.as_contract(contract_id, || {
         env.storage()
@@ -2029,16 +2045,20 @@ fn test_enter_suspended_giveaway_fails() {
                 token,
                 amount: 500,
                 title: String::from_str(&env, "Suspended"),
-                participant_count: 0,
+                start_time: 0,
                 end_time: env.ledger().timestamp() + 3600,
+                max_participants: None,
+                min_participants: 0,
                 status: GiveawayStatus::Suspended,
                 winner_count: 1,
                 winners: Vec::new(&env),
-                verification_type: 0,
-                min_reputation: 0,
+                eligibility: Vec::new(&env),
+                hold_until_draw: false,
                 selection_method: SelectionMethod::Random,
                 claim_deadline: 0,
                 claimed_count: 0,
+                fee_mode: FeeMode::WinnerPays,
+                fee_bps: 100,
             },
         );
     });
@@ -2124,7 +2144,7 @@ fn test_reputation_increments_after_claim_prize() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2175,7 +2195,7 @@ fn test_reputation_accumulates_across_giveaways() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2228,7 +2248,7 @@ fn test_manual_winner_selection() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2241,6 +2261,7 @@ fn test_manual_winner_selection() {
         &2,
         &None,
         &SelectionMethod::Manual,
+        &FeeMode::WinnerPays,
     );
 
     client.enter_giveaway(&participant1, &giveaway_id);
@@ -2293,7 +2314,7 @@ fn test_manual_winner_selection_fails_non_creator() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2306,6 +2327,7 @@ fn test_manual_winner_selection_fails_non_creator() {
         &1,
         &None,
         &SelectionMethod::Manual,
+        &FeeMode::WinnerPays,
     );
 
     client.enter_giveaway(&participant, &giveaway_id);
@@ -2338,7 +2360,7 @@ fn test_merit_winner_selection() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
         env.storage()
             .persistent()
@@ -2360,6 +2382,7 @@ fn test_merit_winner_selection() {
         &2,
         &None,
         &SelectionMethod::Merit,
+        &FeeMode::WinnerPays,
     );
 
     client.enter_giveaway(&participant1, &giveaway_id);
@@ -2368,7 +2391,9 @@ fn test_merit_winner_selection() {
 
     env.ledger().with_mut(|li| li.timestamp += 100);
 
-    let winner = client.finalize_merit_winners(&creator, &giveaway_id);
+    let winner = client
+        .finalize_merit_winners(&creator, &giveaway_id)
+        .unwrap();
     assert_eq!(winner, participant2);
 
     let stored_winners: Vec<Address> = env.as_contract(&contract_id, || {
@@ -2406,7 +2431,7 @@ fn test_pick_winner_fails_on_manual_giveaway() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2419,6 +2444,7 @@ fn test_pick_winner_fails_on_manual_giveaway() {
         &1,
         &None,
         &SelectionMethod::Manual,
+        &FeeMode::WinnerPays,
     );
 
     client.enter_giveaway(&participant, &giveaway_id);
@@ -2449,7 +2475,7 @@ fn test_admin_can_finalize_manual_winners() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2462,6 +2488,7 @@ fn test_admin_can_finalize_manual_winners() {
         &1,
         &None,
         &SelectionMethod::Manual,
+        &FeeMode::WinnerPays,
     );
 
     client.enter_giveaway(&participant, &giveaway_id);
@@ -2490,7 +2517,19 @@ fn test_transfer_admin_success() {
             .set(&DataKey::Admin, &current_admin);
     });
 
-    contract_client.transfer_admin(&current_admin, &new_admin);
+    contract_client.propose_admin(&current_admin, &new_admin);
+
+    // The proposal alone does not hand over control.
+    env.as_contract(&contract_id, || {
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        assert_eq!(stored_admin, current_admin);
+    });
+    assert_eq!(
+        contract_client.get_pending_admin().unwrap().new_admin,
+        new_admin
+    );
+
+    contract_client.accept_admin(&new_admin);
 
     // events().all() only returns events from the last contract invocation
     let events = env.events().all();
@@ -2516,6 +2555,7 @@ fn test_transfer_admin_success() {
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         assert_eq!(stored_admin, new_admin);
     });
+    assert_eq!(contract_client.get_pending_admin(), None);
 }
 
 #[test]
@@ -2531,7 +2571,7 @@ fn test_transfer_admin_fails_when_not_initialized() {
     let new_admin = Address::generate(&env);
 
     // DO NOT initialize admin - should panic
-    contract_client.transfer_admin(&current_admin, &new_admin);
+    contract_client.propose_admin(&current_admin, &new_admin);
 }
 
 #[test]
@@ -2554,7 +2594,7 @@ fn test_transfer_admin_fails_wrong_current_admin() {
     });
 
     // Impostor address does not match stored admin - should panic
-    contract_client.transfer_admin(&impostor, &new_admin);
+    contract_client.propose_admin(&impostor, &new_admin);
 }
 
 #[test]
@@ -2575,7 +2615,8 @@ fn test_new_admin_can_perform_gated_actions_after_transfer() {
             .set(&DataKey::Admin, &current_admin);
     });
 
-    contract_client.transfer_admin(&current_admin, &new_admin);
+    contract_client.propose_admin(&current_admin, &new_admin);
+    contract_client.accept_admin(&new_admin);
 
     // New admin can perform gated actions
     contract_client.add_token(&token);
@@ -2586,13 +2627,104 @@ fn test_new_admin_can_perform_gated_actions_after_transfer() {
 
         let is_allowed: bool = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::AllowedToken(token.clone()))
             .unwrap_or(false);
         assert!(is_allowed);
     });
 }
 
+#[test]
+fn test_accept_admin_rejects_wrong_address_and_expired_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let contract_client = AdminContractClient::new(&env, &contract_id);
+
+    let current_admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let typo = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &current_admin);
+    });
+
+    assert_eq!(
+        contract_client.try_accept_admin(&new_admin),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::NoPendingAdmin as u32
+        )))
+    );
+
+    contract_client.propose_admin(&current_admin, &new_admin);
+    assert_eq!(
+        contract_client.try_accept_admin(&typo),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::NotAdmin as u32
+        )))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += 7 * 24 * 60 * 60 + 1);
+    assert_eq!(
+        contract_client.try_accept_admin(&new_admin),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::ProposalExpired as u32
+        )))
+    );
+
+    env.as_contract(&contract_id, || {
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        assert_eq!(stored_admin, current_admin);
+    });
+}
+
+#[test]
+fn test_cancel_admin_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let contract_client = AdminContractClient::new(&env, &contract_id);
+
+    let current_admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &current_admin);
+    });
+
+    contract_client.propose_admin(&current_admin, &new_admin);
+    contract_client.cancel_admin_proposal();
+
+    let events = env.events().all();
+    let expected_topics: soroban_sdk::Vec<Val> = vec![
+        &env,
+        symbol_short!("admin").into_val(&env),
+        symbol_short!("cancel").into_val(&env),
+        current_admin.into_val(&env),
+    ];
+    assert!(
+        events
+            .iter()
+            .any(|(ec, topics, _)| ec == contract_id && topics == expected_topics.into_val(&env)),
+        "AdminProposalCancelled event was not emitted"
+    );
+
+    assert_eq!(contract_client.get_pending_admin(), None);
+    assert_eq!(
+        contract_client.try_accept_admin(&new_admin),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::NoPendingAdmin as u32
+        )))
+    );
+}
+
 #[test]
 fn test_remove_token_revokes_whitelist() {
     let env = Env::default();
@@ -2609,16 +2741,19 @@ fn test_remove_token_revokes_whitelist() {
     });
 
     contract_client.add_token(&token);
+    assert_eq!(
+        contract_client.list_allowed_tokens(),
+        vec![&env, token.clone()]
+    );
     contract_client.remove_token(&token);
 
     env.as_contract(&contract_id, || {
-        let is_allowed: bool = env
+        assert!(!env
             .storage()
-            .instance()
-            .get(&DataKey::AllowedToken(token.clone()))
-            .unwrap_or(false);
-        assert!(!is_allowed);
+            .persistent()
+            .has(&DataKey::AllowedToken(token.clone())));
     });
+    assert_eq!(contract_client.list_allowed_tokens(), Vec::new(&env));
 }
 
 #[test]
@@ -2655,7 +2790,7 @@ fn test_create_giveaway_succeeds_before_and_fails_after_delist() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2674,8 +2809,8 @@ fn test_create_giveaway_succeeds_before_and_fails_after_delist() {
     // Delist token (same persistence as AdminContract::remove_token)
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
-            .set(&DataKey::AllowedToken(mock_token.clone()), &false);
+            .persistent()
+            .remove(&DataKey::AllowedToken(mock_token.clone()));
     });
 
     // Creation with delisted token must fail
@@ -2711,7 +2846,7 @@ fn test_existing_giveaway_continues_after_token_delist() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2728,8 +2863,8 @@ fn test_existing_giveaway_continues_after_token_delist() {
     // Delist after funding — existing giveaway lifecycle remains supported
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
-            .set(&DataKey::AllowedToken(mock_token.clone()), &false);
+            .persistent()
+            .remove(&DataKey::AllowedToken(mock_token.clone()));
     });
 
     contract_client.enter_giveaway(&participant, &giveaway_id);
@@ -2738,7 +2873,7 @@ fn test_existing_giveaway_continues_after_token_delist() {
         li.timestamp += 100;
     });
 
-    let winner = contract_client.pick_winner(&giveaway_id);
+    let winner = contract_client.pick_winner(&giveaway_id).unwrap();
     assert_eq!(winner, participant);
 }
 
@@ -2765,7 +2900,7 @@ fn test_claim_prize_after_expiry_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2811,7 +2946,7 @@ fn test_claim_prize_twice_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2855,7 +2990,7 @@ fn test_claim_prize_by_non_winner_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2872,7 +3007,7 @@ fn test_claim_prize_by_non_winner_fails() {
     contract_client.enter_giveaway(&participant1, &giveaway_id);
     contract_client.enter_giveaway(&participant2, &giveaway_id);
     env.ledger().with_mut(|li| li.timestamp += 100);
-    let winner = contract_client.pick_winner(&giveaway_id);
+    let winner = contract_client.pick_winner(&giveaway_id).unwrap();
     let non_winner = if winner == participant1 {
         participant2
     } else {
@@ -2903,7 +3038,7 @@ fn test_recover_before_expiry_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -2945,7 +3080,7 @@ fn test_recover_after_expiry_succeeds() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -3007,7 +3142,7 @@ fn test_recover_by_unauthorized_caller_fails() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -3053,7 +3188,7 @@ fn test_partial_claim_recovery() {
 
     env.as_contract(&contract_id, || {
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::AllowedToken(mock_token.clone()), &true);
     });
 
@@ -3478,16 +3613,20 @@ fn seed_active_giveaway_with_creator(
         token: token.clone(),
         amount: 500,
         title: String::from_str(env, "Appeal Test Giveaway"),
-        participant_count: 0,
+        start_time: 0,
         end_time: env.ledger().timestamp() + 3600,
+        max_participants: None,
+        min_participants: 0,
         status: GiveawayStatus::Active,
         winner_count: 1,
         winners: Vec::new(env),
-        verification_type: 0,
-        min_reputation: 0,
+        eligibility: Vec::new(env),
+        hold_until_draw: false,
         selection_method: SelectionMethod::Random,
         claim_deadline: 0,
         claimed_count: 0,
+        fee_mode: FeeMode::WinnerPays,
+        fee_bps: 100,
     };
     env.as_contract(contract_id, || {
         env.storage()
@@ -3704,7 +3843,7 @@ fn test_resolve_appeal_restore_true_sets_giveaway_active() {
     });
 
     // Admin resolves: restore = true.
-    admin_client.resolve_appeal(&giveaway_id, &true);
+    admin_client.resolve_appeal(&admin, &giveaway_id, &true);
 
     env.as_contract(&admin_contract_id, || {
         let g: Giveaway = env
@@ -3748,22 +3887,26 @@ fn test_resolve_appeal_restore_false_keeps_giveaway_suspended() {
                 token,
                 amount: 500,
                 title: String::from_str(&env, "Denied Appeal Giveaway"),
-                participant_count: 0,
+                start_time: 0,
                 end_time: env.ledger().timestamp() + 3600,
+                max_participants: None,
+                min_participants: 0,
                 status: GiveawayStatus::UnderAppeal,
                 winner_count: 1,
                 winners: Vec::new(&env),
-                verification_type: 0,
-                min_reputation: 0,
+                eligibility: Vec::new(&env),
+                hold_until_draw: false,
                 selection_method: SelectionMethod::Random,
                 claim_deadline: 0,
                 claimed_count: 0,
+                fee_mode: FeeMode::WinnerPays,
+                fee_bps: 100,
             },
         );
     });
 
     // Admin rejects the appeal.
-    admin_client.resolve_appeal(&giveaway_id, &false);
+    admin_client.resolve_appeal(&admin, &giveaway_id, &false);
 
     env.as_contract(&admin_contract_id, || {
         let g: Giveaway = env
@@ -3815,7 +3958,7 @@ fn test_resolve_appeal_restore_true_sets_help_request_open() {
         );
     });
 
-    admin_client.resolve_appeal(&request_id, &true);
+    admin_client.resolve_appeal(&admin, &request_id, &true);
 
     env.as_contract(&admin_contract_id, || {
         let r: HelpRequest = env
@@ -3839,7 +3982,7 @@ fn test_resolve_appeal_by_non_admin_panics() {
     let contract_id = env.register(AdminContract, ());
     let client = AdminContractClient::new(&env, &contract_id);
 
-    client.resolve_appeal(&1u64, &true);
+    client.resolve_appeal(&Address::generate(&env), &1u64, &true);
 }
 
 // ── 9. Flag history is preserved after restore ───────────────────────────────
@@ -3990,21 +4133,25 @@ fn test_resolve_appeal_emits_appeal_resolved_event() {
                 token,
                 amount: 500,
                 title: String::from_str(&env, "Event Test Giveaway"),
-                participant_count: 0,
+                start_time: 0,
                 end_time: env.ledger().timestamp() + 3600,
+                max_participants: None,
+                min_participants: 0,
                 status: GiveawayStatus::UnderAppeal,
                 winner_count: 1,
                 winners: Vec::new(&env),
-                verification_type: 0,
-                min_reputation: 0,
+                eligibility: Vec::new(&env),
+                hold_until_draw: false,
                 selection_method: SelectionMethod::Random,
                 claim_deadline: 0,
                 claimed_count: 0,
+                fee_mode: FeeMode::WinnerPays,
+                fee_bps: 100,
             },
         );
     });
 
-    client.resolve_appeal(&giveaway_id, &true);
+    client.resolve_appeal(&admin, &giveaway_id, &true);
 
     let events = env.events().all();
     let expected_topics: soroban_sdk::Vec<Val> = vec![
@@ -4055,3 +4202,4643 @@ fn test_file_appeal_emits_content_appealed_event() {
         "ContentAppealed event was not emitted"
     );
 }
+
+// ── fee mode tests ────────────────────────────────────────────────────────────
+
+/// Register the giveaway contract at 1% fee with an allowlisted token.
+/// Returns `(contract_id, client, token)`.
+fn setup_giveaway_contract(env: &Env) -> (Address, GiveawayContractClient<'_>, Address) {
+    let contract_id = env.register(GiveawayContract, ());
+    let client = GiveawayContractClient::new(env, &contract_id);
+    client.init(&Address::generate(env), &100u32);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(token.clone()), &true);
+    });
+
+    (contract_id, client, token)
+}
+
+/// The `prize_amount` carried by the `GiveawayWinnerSelected` event for `winner`.
+fn winner_event_prize(env: &Env, contract_id: &Address, winner: &Address) -> i128 {
+    let expected_topics: soroban_sdk::Vec<Val> = vec![
+        env,
+        symbol_short!("giveaway").into_val(env),
+        symbol_short!("winner").into_val(env),
+        winner.into_val(env),
+    ];
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(ec, topics, _)| ec == contract_id && *topics == expected_topics)
+        .expect("GiveawayWinnerSelected event was not emitted");
+    let data_vec: soroban_sdk::Vec<Val> = soroban_sdk::Vec::from_val(env, &data);
+    i128::from_val(env, &data_vec.get(1).unwrap())
+}
+
+#[test]
+fn test_creator_pays_fee_upfront_and_winner_gets_full_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    let winner = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1000);
+
+    let giveaway_id = client.create_giveaway_with_selection(
+        &creator,
+        &token,
+        &500,
+        &String::from_str(&env, "Creator Pays"),
+        &60,
+        &1,
+        &None,
+        &SelectionMethod::Random,
+        &FeeMode::CreatorPays,
+    );
+
+    // 500 escrowed plus the 1% fee on the whole prize, held until the draw.
+    assert_eq!(token_client.balance(&creator), 495);
+    assert_eq!(token_client.balance(&contract_id), 505);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&DataKey::CollectedFees(token.clone())));
+        assert_eq!(
+            storage.get::<_, i128>(&EntryKey::PendingFee(giveaway_id)),
+            Some(5)
+        );
+    });
+
+    client.enter_giveaway(&winner, &giveaway_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+    assert_eq!(winner_event_prize(&env, &contract_id, &winner), 500);
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&EntryKey::PendingFee(giveaway_id)));
+    });
+
+    client.claim_prize(&giveaway_id, &winner);
+
+    assert_eq!(token_client.balance(&winner), 500);
+    assert_eq!(token_client.balance(&contract_id), 5);
+    env.as_contract(&contract_id, || {
+        let collected: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CollectedFees(token.clone()))
+            .unwrap();
+        assert_eq!(collected, 5);
+    });
+}
+
+#[test]
+fn test_winner_pays_event_reports_net_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    let winner = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &500);
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &500,
+        &String::from_str(&env, "Winner Pays"),
+        &60,
+        &1,
+        &None,
+    );
+    assert_eq!(token_client.balance(&creator), 0);
+
+    client.enter_giveaway(&winner, &giveaway_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+    assert_eq!(winner_event_prize(&env, &contract_id, &winner), 495);
+
+    client.claim_prize(&giveaway_id, &winner);
+    assert_eq!(token_client.balance(&winner), 495);
+}
+
+#[test]
+fn test_fee_rate_is_fixed_at_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    let winner = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &500);
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &500,
+        &String::from_str(&env, "Fixed Fee"),
+        &60,
+        &1,
+        &None,
+    );
+
+    // Raising the fee afterwards must not change the advertised payout.
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Fee, &1_000u32);
+    });
+
+    client.enter_giveaway(&winner, &giveaway_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+    client.claim_prize(&giveaway_id, &winner);
+
+    assert_eq!(token_client.balance(&winner), 495);
+}
+
+// ── fee discount tests ────────────────────────────────────────────────────────
+
+#[test]
+fn test_reputation_tier_discount_applies_at_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    let winner = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1000);
+
+    env.as_contract(&contract_id, || {
+        let tiers = vec![
+            &env,
+            FeeTier {
+                min_reputation: 3,
+                fee_bps: 50,
+            },
+            FeeTier {
+                min_reputation: 10,
+                fee_bps: 25,
+            },
+        ];
+        env.storage().instance().set(&DataKey::FeeSchedule, &tiers);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reputation(creator.clone()), &5u64);
+    });
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1000,
+        &String::from_str(&env, "Tiered"),
+        &60,
+        &1,
+        &None,
+    );
+
+    // Reputation gains after creation must not change the recorded rate.
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reputation(creator.clone()), &50u64);
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Giveaway(giveaway_id))
+            .unwrap();
+        assert_eq!(giveaway.fee_bps, 50);
+    });
+
+    client.enter_giveaway(&winner, &giveaway_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+    client.claim_prize(&giveaway_id, &winner);
+
+    // 0.5% of 1000 instead of the 1% base fee.
+    assert_eq!(token_client.balance(&winner), 995);
+}
+
+#[test]
+fn test_fee_override_takes_precedence_over_tiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let nonprofit = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&nonprofit, &1000);
+
+    env.as_contract(&contract_id, || {
+        let tiers = vec![
+            &env,
+            FeeTier {
+                min_reputation: 0,
+                fee_bps: 50,
+            },
+        ];
+        env.storage().instance().set(&DataKey::FeeSchedule, &tiers);
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeeOverride(nonprofit.clone()), &0u32);
+    });
+
+    client.create_giveaway_with_selection(
+        &nonprofit,
+        &token,
+        &1000,
+        &String::from_str(&env, "Nonprofit"),
+        &60,
+        &1,
+        &None,
+        &SelectionMethod::Random,
+        &FeeMode::CreatorPays,
+    );
+
+    assert_eq!(token_client.balance(&nonprofit), 0);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+}
+
+#[test]
+fn test_admin_manages_fee_schedule_and_overrides() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let client = AdminContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    });
+
+    let tiers = vec![
+        &env,
+        FeeTier {
+            min_reputation: 5,
+            fee_bps: 75,
+        },
+        FeeTier {
+            min_reputation: 20,
+            fee_bps: 40,
+        },
+    ];
+    client.set_fee_schedule(&tiers);
+    assert_eq!(client.get_fee_schedule(), tiers);
+
+    let unordered = vec![
+        &env,
+        FeeTier {
+            min_reputation: 20,
+            fee_bps: 40,
+        },
+        FeeTier {
+            min_reputation: 5,
+            fee_bps: 75,
+        },
+    ];
+    assert_eq!(
+        client.try_set_fee_schedule(&unordered),
+        Err(Ok(contract_error(Error::InvalidFeeConfig)))
+    );
+
+    let nonprofit = Address::generate(&env);
+    assert_eq!(client.get_fee_override(&nonprofit), None);
+    client.set_fee_override(&nonprofit, &0);
+    assert_eq!(client.get_fee_override(&nonprofit), Some(0));
+    assert_eq!(
+        client.try_set_fee_override(&nonprofit, &10_001),
+        Err(Ok(contract_error(Error::InvalidFeeConfig)))
+    );
+    client.remove_fee_override(&nonprofit);
+    assert_eq!(client.get_fee_override(&nonprofit), None);
+}
+
+#[test]
+#[should_panic]
+fn test_set_fee_schedule_fails_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let client = AdminContractClient::new(&env, &contract_id);
+
+    // DO NOT initialize admin - should panic
+    client.set_fee_schedule(&Vec::new(&env));
+}
+
+// ── escrow liability tests ────────────────────────────────────────────────────
+
+fn liabilities(env: &Env, contract_id: &Address, token: &Address) -> i128 {
+    env.as_contract(contract_id, || crate::escrow::get_liabilities(env, token))
+}
+
+#[test]
+fn test_giveaway_lifecycle_tracks_liabilities() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    let winner = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1000);
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &500,
+        &String::from_str(&env, "Liabilities"),
+        &60,
+        &1,
+        &None,
+    );
+    assert_eq!(liabilities(&env, &contract_id, &token), 500);
+
+    client.enter_giveaway(&winner, &giveaway_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+    client.claim_prize(&giveaway_id, &winner);
+
+    // Only the collected fee is still owed.
+    assert_eq!(liabilities(&env, &contract_id, &token), 5);
+
+    let admin: Address = env.as_contract(&contract_id, || {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    });
+    client.withdraw_fees(&admin, &token);
+    assert_eq!(liabilities(&env, &contract_id, &token), 0);
+}
+
+#[test]
+fn test_donations_and_refunds_track_liabilities() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(MutualAidContract, ());
+    let client = MutualAidContractClient::new(&env, &contract_id);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let creator = Address::generate(&env);
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &1000);
+
+    let request_id = client.post_help_request(&creator, &1, &1000, &token);
+    client.donate(&donor, &request_id, &400);
+    assert_eq!(liabilities(&env, &contract_id, &token), 400);
+
+    client.cancel_request(&creator, &request_id);
+    client.claim_refund(&donor, &request_id);
+    assert_eq!(liabilities(&env, &contract_id, &token), 0);
+}
+
+#[test]
+fn test_admin_withdraw_limited_to_surplus_outside_emergency() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let client = AdminContractClient::new(&env, &contract_id);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_client = token::Client::new(&env, &token);
+    let admin = Address::generate(&env);
+    let safe_address = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        crate::escrow::increase_liabilities(&env, &token, 800);
+    });
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    assert_eq!(
+        client.get_solvency(&token),
+        Solvency {
+            balance: 1000,
+            liabilities: 800,
+            surplus: 200,
+        }
+    );
+
+    assert_eq!(
+        client.try_admin_withdraw(&token, &201, &safe_address),
+        Err(Ok(contract_error(Error::InsufficientSurplus)))
+    );
+    client.admin_withdraw(&token, &200, &safe_address);
+    assert_eq!(token_client.balance(&safe_address), 200);
+
+    client.set_emergency(&true);
+    assert!(client.is_emergency());
+    client.admin_withdraw(&token, &800, &safe_address);
+    assert_eq!(token_client.balance(&safe_address), 1000);
+
+    // The escrow is still owed, so monitoring shows the shortfall.
+    assert_eq!(client.get_solvency(&token).surplus, -800);
+}
+
+// ── pause & circuit breaker tests ─────────────────────────────────────────────
+
+fn set_pause_mode(env: &Env, contract_id: &Address, mode: PauseMode) {
+    env.as_contract(contract_id, || {
+        env.storage().instance().set(&DataKey::PauseMode, &mode);
+    });
+}
+
+#[test]
+fn test_withdraw_only_pause_blocks_entries_but_not_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    let winner = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1000);
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &500,
+        &String::from_str(&env, "Paused"),
+        &60,
+        &1,
+        &None,
+    );
+    client.enter_giveaway(&winner, &giveaway_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+
+    set_pause_mode(&env, &contract_id, PauseMode::WithdrawOnly);
+
+    assert_eq!(
+        client.try_create_giveaway(
+            &creator,
+            &token,
+            &500,
+            &String::from_str(&env, "Blocked"),
+            &60,
+            &1,
+            &None,
+        ),
+        Err(Ok(contract_error(Error::Paused)))
+    );
+
+    // Exits keep working so the winner is not trapped.
+    client.claim_prize(&giveaway_id, &winner);
+    assert_eq!(token_client.balance(&winner), 495);
+}
+
+#[test]
+fn test_full_pause_blocks_exits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _token, creator, request_id) = setup_funded_request(&env);
+    set_pause_mode(&env, &contract_id, PauseMode::Full);
+
+    assert_eq!(
+        client.try_claim_help_request_funds(&creator, &request_id),
+        Err(Ok(contract_error(Error::Paused)))
+    );
+
+    set_pause_mode(&env, &contract_id, PauseMode::Unpaused);
+    client.claim_help_request_funds(&creator, &request_id);
+}
+
+#[test]
+fn test_module_pause_blocks_only_that_module() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(GovernanceContract, ());
+    let gov = GovernanceContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::ModulePaused(PauseModule::Flagging), &true);
+        assert!(crate::pause::is_paused(&env, PauseModule::Flagging));
+        assert!(!crate::pause::is_paused(&env, PauseModule::Donations));
+    });
+
+    assert_eq!(
+        gov.try_flag_content(&user, &ContentType::Giveaway, &1),
+        Err(Ok(Error::Paused))
+    );
+    assert_eq!(gov.get_flag_count(&ContentType::Giveaway, &1), 0);
+}
+
+#[test]
+fn test_pauser_can_pause_and_others_cannot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let client = AdminContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    });
+
+    client.grant_role(&Role::Pauser, &pauser);
+
+    client.set_pause_mode(&pauser, &PauseMode::WithdrawOnly);
+    assert_eq!(client.get_pause_mode(), PauseMode::WithdrawOnly);
+    assert!(client.is_paused(&PauseModule::Donations));
+    assert!(!client.is_paused(&PauseModule::Refunds));
+
+    client.set_module_paused(&admin, &PauseModule::Refunds, &true);
+    assert!(client.is_paused(&PauseModule::Refunds));
+
+    assert_eq!(
+        client.try_set_pause_mode(&stranger, &PauseMode::Unpaused),
+        Err(Ok(contract_error(Error::MissingRole)))
+    );
+    assert_eq!(client.get_pause_mode(), PauseMode::WithdrawOnly);
+}
+
+// ── role-based access tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_roles_gate_privileged_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let client = AdminContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let token = Address::generate(&env);
+    let request_id: u64 = 7;
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        let now = env.ledger().timestamp();
+        let request = HelpRequest {
+            id: request_id,
+            creator: creator.clone(),
+            token: token.clone(),
+            goal: 1000,
+            raised_amount: 0,
+            status: HelpRequestStatus::Open,
+            is_verified: false,
+            created_at: now,
+            expires_at: Some(now + 30 * 24 * 60 * 60),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::HelpRequest(request_id), &request);
+    });
+
+    client.grant_role(&Role::Moderator, &moderator);
+    client.grant_role(&Role::Verifier, &verifier);
+    assert!(client.has_role(&Role::Moderator, &moderator));
+    assert!(client.has_role(&Role::Treasurer, &admin));
+    assert!(!client.has_role(&Role::Moderator, &verifier));
+    assert_eq!(
+        client.get_role_members(&Role::Moderator),
+        vec![&env, moderator.clone()]
+    );
+
+    // A verifier may only verify, not toggle the badge off again.
+    client.verify_help_request(&verifier, &request_id);
+    assert_eq!(
+        client.try_toggle_request_verification(&verifier, &request_id),
+        Err(Ok(contract_error(Error::MissingRole)))
+    );
+    client.toggle_request_verification(&moderator, &request_id);
+    env.as_contract(&contract_id, || {
+        let request: HelpRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::HelpRequest(request_id))
+            .unwrap();
+        assert!(!request.is_verified);
+    });
+
+    client.revoke_role(&Role::Moderator, &moderator);
+    assert!(!client.has_role(&Role::Moderator, &moderator));
+    assert_eq!(
+        client.try_toggle_request_verification(&moderator, &request_id),
+        Err(Ok(contract_error(Error::MissingRole)))
+    );
+    assert_eq!(
+        client.try_grant_role(&Role::Admin, &moderator),
+        Err(Ok(contract_error(Error::InvalidRole)))
+    );
+}
+
+#[test]
+fn test_treasurer_withdraws_fees_to_self() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let treasurer = Address::generate(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1010);
+
+    let giveaway_id = client.create_giveaway_with_selection(
+        &creator,
+        &token,
+        &1000,
+        &String::from_str(&env, "Treasury"),
+        &60,
+        &1,
+        &None,
+        &SelectionMethod::Random,
+        &FeeMode::CreatorPays,
+    );
+    // The upfront fee is collected once a winner is drawn.
+    client.enter_giveaway(&Address::generate(&env), &giveaway_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+
+    assert_eq!(
+        client.try_withdraw_fees(&treasurer, &token),
+        Err(Ok(contract_error(Error::MissingRole)))
+    );
+
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &DataKey::RoleMembers(Role::Treasurer),
+            &vec![&env, treasurer.clone()],
+        );
+    });
+    client.withdraw_fees(&treasurer, &token);
+    assert_eq!(token_client.balance(&treasurer), 10);
+}
+
+// ── multisig tests ────────────────────────────────────────────────────────────
+
+/// Register the multisig contract with an admin and a 2-of-3 signer set.
+/// Returns `(contract_id, client, signers)`.
+fn setup_multisig(env: &Env) -> (Address, MultisigContractClient<'_>, Vec<Address>) {
+    let contract_id = env.register(MultisigContract, ());
+    let client = MultisigContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    });
+
+    let signers = vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    client.init_multisig(&signers, &2);
+
+    (contract_id, client, signers)
+}
+
+#[test]
+fn test_multisig_add_token_requires_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, signers) = setup_multisig(&env);
+    let token = Address::generate(&env);
+
+    let proposal_id = client.submit_proposal(
+        &signers.get(0).unwrap(),
+        &AdminAction::AddToken(token.clone()),
+    );
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(contract_error(Error::ThresholdNotMet)))
+    );
+    assert_eq!(
+        client.try_approve_proposal(&signers.get(0).unwrap(), &proposal_id),
+        Err(Ok(contract_error(Error::AlreadyApproved)))
+    );
+    assert_eq!(
+        client.try_approve_proposal(&Address::generate(&env), &proposal_id),
+        Err(Ok(contract_error(Error::NotSigner)))
+    );
+
+    client.approve_proposal(&signers.get(2).unwrap(), &proposal_id);
+    client.execute_proposal(&proposal_id);
+
+    env.as_contract(&contract_id, || {
+        let is_allowed: bool = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AllowedToken(token.clone()))
+            .unwrap_or(false);
+        assert!(is_allowed);
+    });
+    assert!(client.get_proposal(&proposal_id).unwrap().executed);
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+}
+
+#[test]
+fn test_multisig_withdraw_and_signer_rotation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, signers) = setup_multisig(&env);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_client = token::Client::new(&env, &token);
+    let safe_address = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    let withdraw = AdminAction::Withdraw(token.clone(), 400, safe_address.clone());
+    let proposal_id = client.submit_proposal(&signers.get(1).unwrap(), &withdraw);
+    client.approve_proposal(&signers.get(2).unwrap(), &proposal_id);
+    client.execute_proposal(&proposal_id);
+    assert_eq!(token_client.balance(&safe_address), 400);
+
+    // Rotating to a 1-of-1 set goes through the same approval flow.
+    let new_signer = Address::generate(&env);
+    let rotate = AdminAction::UpdateSigners(vec![&env, new_signer.clone()], 1);
+    let proposal_id = client.submit_proposal(&signers.get(0).unwrap(), &rotate);
+    client.approve_proposal(&signers.get(1).unwrap(), &proposal_id);
+    client.execute_proposal(&proposal_id);
+    assert_eq!(
+        client.get_multisig_config(),
+        Some(MultisigConfig {
+            signers: vec![&env, new_signer.clone()],
+            threshold: 1,
+        })
+    );
+    assert_eq!(
+        client.try_submit_proposal(&signers.get(0).unwrap(), &withdraw),
+        Err(Ok(contract_error(Error::NotSigner)))
+    );
+}
+
+#[test]
+fn test_multisig_proposal_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_contract_id, client, signers) = setup_multisig(&env);
+    let proposal_id = client.submit_proposal(
+        &signers.get(0).unwrap(),
+        &AdminAction::AddToken(Address::generate(&env)),
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += 7 * 24 * 60 * 60 + 1);
+    assert_eq!(
+        client.try_approve_proposal(&signers.get(1).unwrap(), &proposal_id),
+        Err(Ok(contract_error(Error::ProposalExpired)))
+    );
+}
+
+#[test]
+fn test_single_key_paths_blocked_once_multisig_configured() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let client = AdminContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(
+            &DataKey::MultisigConfig,
+            &MultisigConfig {
+                signers: vec![&env, admin.clone()],
+                threshold: 1,
+            },
+        );
+    });
+
+    assert_eq!(
+        client.try_add_token(&Address::generate(&env)),
+        Err(Ok(contract_error(Error::MultisigRequired)))
+    );
+    assert_eq!(
+        client.try_propose_admin(&admin, &Address::generate(&env)),
+        Err(Ok(contract_error(Error::MultisigRequired)))
+    );
+}
+
+// ── timelock tests ────────────────────────────────────────────────────────────
+
+const TEST_TIMELOCK_DELAY: u64 = 2 * 24 * 60 * 60;
+
+/// Register the admin and timelock contracts with an admin and a 2-day delay.
+/// Returns `(timelock_id, timelock_client, admin_client, admin)`.
+fn setup_timelock(
+    env: &Env,
+) -> (
+    Address,
+    TimelockContractClient<'_>,
+    AdminContractClient<'_>,
+    Address,
+) {
+    let contract_id = env.register(TimelockContract, ());
+    let client = TimelockContractClient::new(env, &contract_id);
+    let admin_id = env.register(AdminContract, ());
+    let admin_client = AdminContractClient::new(env, &admin_id);
+    let admin = Address::generate(env);
+    for id in [&contract_id, &admin_id] {
+        env.as_contract(id, || {
+            env.storage().instance().set(&DataKey::Admin, &admin);
+            env.storage()
+                .instance()
+                .set(&DataKey::TimelockDelay, &TEST_TIMELOCK_DELAY);
+        });
+    }
+
+    (contract_id, client, admin_client, admin)
+}
+
+#[test]
+fn test_timelock_withdraw_runs_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _, _) = setup_timelock(&env);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_client = token::Client::new(&env, &token);
+    let safe_address = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    let action_id = client.queue_action(&TimelockAction::Withdraw(
+        token.clone(),
+        300,
+        safe_address.clone(),
+    ));
+    let queued = client.get_queued_action(&action_id).unwrap();
+    assert_eq!(queued.eta, env.ledger().timestamp() + TEST_TIMELOCK_DELAY);
+    assert_eq!(queued.status, TimelockStatus::Queued);
+
+    assert_eq!(
+        client.try_execute_action(&action_id),
+        Err(Ok(contract_error(Error::TimelockNotReady)))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += TEST_TIMELOCK_DELAY);
+    client.execute_action(&action_id);
+    assert_eq!(token_client.balance(&safe_address), 300);
+    assert_eq!(
+        client.get_queued_action(&action_id).unwrap().status,
+        TimelockStatus::Executed
+    );
+    assert_eq!(
+        client.try_execute_action(&action_id),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+}
+
+#[test]
+fn test_timelock_cancel_and_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_contract_id, client, _, _) = setup_timelock(&env);
+
+    let cancelled = client.queue_action(&TimelockAction::SetFee(500));
+    client.cancel_action(&cancelled);
+    env.ledger()
+        .with_mut(|li| li.timestamp += TEST_TIMELOCK_DELAY);
+    assert_eq!(
+        client.try_execute_action(&cancelled),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    let stale = client.queue_action(&TimelockAction::SetFee(500));
+    env.ledger()
+        .with_mut(|li| li.timestamp += TEST_TIMELOCK_DELAY + 14 * 24 * 60 * 60 + 1);
+    assert_eq!(
+        client.try_execute_action(&stale),
+        Err(Ok(contract_error(Error::ProposalExpired)))
+    );
+    assert_eq!(
+        client.try_execute_action(&99),
+        Err(Ok(contract_error(Error::ProposalNotFound)))
+    );
+}
+
+#[test]
+fn test_timelock_blocks_direct_critical_calls() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_contract_id, client, admin_client, admin) = setup_timelock(&env);
+
+    assert_eq!(
+        admin_client.try_admin_withdraw(&Address::generate(&env), &1, &admin),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+    assert_eq!(
+        admin_client.try_remove_token(&Address::generate(&env)),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+    assert_eq!(
+        admin_client.try_propose_admin(&admin, &Address::generate(&env)),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+
+    // Raising the fee needs the queue; lowering it does not.
+    assert_eq!(
+        admin_client.try_set_fee(&500),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+    admin_client.set_fee(&50);
+
+    // The delay itself can grow directly but only shrink through the queue.
+    assert_eq!(
+        client.try_set_timelock_delay(&0),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+    assert_eq!(
+        client.try_set_timelock_delay(&(31 * 24 * 60 * 60)),
+        Err(Ok(contract_error(Error::InvalidDelay)))
+    );
+    client.set_timelock_delay(&(TEST_TIMELOCK_DELAY * 2));
+    assert_eq!(client.get_timelock_delay(), TEST_TIMELOCK_DELAY * 2);
+
+    let action_id = client.queue_action(&TimelockAction::SetDelay(0));
+    env.ledger()
+        .with_mut(|li| li.timestamp += TEST_TIMELOCK_DELAY * 2);
+    client.execute_action(&action_id);
+    assert_eq!(client.get_timelock_delay(), 0);
+}
+
+#[test]
+fn test_timelock_guards_fee_tier_and_override_increases() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, admin_client, _) = setup_timelock(&env);
+    let tier = |min_reputation: u64, fee_bps: u32| FeeTier {
+        min_reputation,
+        fee_bps,
+    };
+
+    // Schedules that only lower some rates apply directly.
+    admin_client.set_fee_schedule(&vec![&env, tier(10, 50)]);
+    admin_client.set_fee_schedule(&vec![&env, tier(5, 50)]);
+    for raise in [
+        vec![&env, tier(5, 80)],
+        // Reputation 5 to 9 would pay the 100 bps base fee again.
+        vec![&env, tier(10, 50)],
+    ] {
+        assert_eq!(
+            admin_client.try_set_fee_schedule(&raise),
+            Err(Ok(contract_error(Error::TimelockRequired)))
+        );
+    }
+
+    let creator = Address::generate(&env);
+    admin_client.set_fee_override(&creator, &20);
+    assert_eq!(
+        admin_client.try_set_fee_override(&creator, &60),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+    assert_eq!(
+        admin_client.try_remove_fee_override(&creator),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+
+    let schedule_id = client.queue_action(&TimelockAction::SetFeeSchedule(vec![&env, tier(5, 80)]));
+    let override_id = client.queue_action(&TimelockAction::SetFeeOverride(creator.clone(), 60));
+    env.ledger()
+        .with_mut(|li| li.timestamp += TEST_TIMELOCK_DELAY);
+    client.execute_action(&schedule_id);
+    client.execute_action(&override_id);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            AdminContract::get_fee_schedule(env.clone()),
+            vec![&env, tier(5, 80)]
+        );
+        assert_eq!(
+            AdminContract::get_fee_override(env.clone(), creator.clone()),
+            Some(60)
+        );
+    });
+}
+
+// ── upgrade & migration tests ─────────────────────────────────────────────────
+
+use crate::eligibility::{
+    add_allowlist_member, allowlist, is_allowlisted, remove_allowlist_member,
+};
+use crate::giveaway::DEFAULT_FEE_BPS;
+use crate::participants::{
+    participant_at, participant_chunk, participant_count, PARTICIPANT_CHUNK_SIZE,
+};
+use crate::types::VerificationType;
+
+/// `Giveaway` as stored by schema v1, before `verification_type` was typed.
+#[derive(Clone)]
+#[contracttype]
+pub struct GiveawayV1 {
+    pub id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub title: String,
+    pub participant_count: u32,
+    pub end_time: u64,
+    pub status: GiveawayStatus,
+    pub winner_count: u32,
+    pub winners: Vec<Address>,
+    pub verification_type: u32,
+    pub min_reputation: u64,
+    pub selection_method: SelectionMethod,
+    pub claim_deadline: u64,
+    pub claimed_count: u32,
+}
+
+fn giveaway_v1(env: &Env, id: u64, verification_type: u32) -> GiveawayV1 {
+    GiveawayV1 {
+        id,
+        creator: Address::generate(env),
+        token: Address::generate(env),
+        amount: 500,
+        title: String::from_str(env, "Legacy Giveaway"),
+        participant_count: 2,
+        end_time: env.ledger().timestamp() + 3600,
+        status: GiveawayStatus::Active,
+        winner_count: 1,
+        winners: Vec::new(env),
+        verification_type,
+        min_reputation: 7,
+        selection_method: SelectionMethod::Random,
+        claim_deadline: 0,
+        claimed_count: 0,
+    }
+}
+
+/// Register the upgrade contract as a live v1 deployment: an admin, three
+/// giveaways in the v1 layout (codes 1, 2 and 0) with two participants each,
+/// and an open help request.
+fn setup_v1_deployment(env: &Env) -> (Address, UpgradeContractClient<'_>) {
+    let contract_id = env.register(UpgradeContract, ());
+    let client = UpgradeContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        for (id, code) in [(1u64, 1u32), (2, 2), (3, 0)] {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Giveaway(id), &giveaway_v1(env, id, code));
+            for index in 0..2 {
+                env.storage().persistent().set(
+                    &DataKey::ParticipantIndex(id, index),
+                    &Address::generate(env),
+                );
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::GiveawayCounter, &3u64);
+    });
+    seed_open_request_with_creator(
+        env,
+        &contract_id,
+        1,
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+
+    (contract_id, client)
+}
+
+#[test]
+fn test_migrate_converts_v1_giveaways_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_v1_deployment(&env);
+    assert_eq!(client.get_schema_version(), 1);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Fee, &250u32);
+    });
+
+    // Each giveaway costs one unit per participant moved, plus one once
+    // they all are.
+    assert!(!client.migrate(&3, &Vec::new(&env), &Vec::new(&env)));
+    assert_eq!(client.get_schema_version(), 1);
+    assert!(!client.migrate(&3, &Vec::new(&env), &Vec::new(&env)));
+    assert!(client.migrate(&3, &Vec::new(&env), &Vec::new(&env)));
+    assert_eq!(client.get_schema_version(), CURRENT_SCHEMA_VERSION);
+
+    env.as_contract(&contract_id, || {
+        let expected = [
+            vec![&env, EligibilityRule::Allowlisted],
+            vec![&env, EligibilityRule::MinReputation(7)],
+            Vec::new(&env),
+        ];
+        for (i, eligibility) in expected.into_iter().enumerate() {
+            let giveaway: Giveaway = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Giveaway(i as u64 + 1))
+                .unwrap();
+            assert_eq!(giveaway.eligibility, eligibility);
+            assert_eq!(giveaway.fee_mode, FeeMode::WinnerPays);
+            assert_eq!(giveaway.fee_bps, 250);
+            assert_eq!(participant_count(&env, i as u64 + 1), 2);
+        }
+
+        let request: HelpRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::HelpRequest(1))
+            .unwrap();
+        assert_eq!(request.status, HelpRequestStatus::Open);
+        assert!(!env.storage().instance().has(&DataKey::MigrationCursor));
+    });
+
+    // A finished migration is a no-op.
+    assert!(client.migrate(&1, &Vec::new(&env), &Vec::new(&env)));
+}
+
+#[test]
+fn test_migrate_progresses_with_a_budget_of_one() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_v1_deployment(&env);
+
+    // Every call moves one participant; a giveaway whose last participant
+    // used up the budget is done without its own unit.
+    for moved in 1..6u32 {
+        assert!(!client.migrate(&1, &Vec::new(&env), &Vec::new(&env)));
+        env.as_contract(&contract_id, || {
+            let giveaway_id = (moved as u64 - 1) / 2 + 1;
+            assert_eq!(participant_count(&env, giveaway_id), (moved - 1) % 2 + 1);
+        });
+    }
+    assert!(client.migrate(&1, &Vec::new(&env), &Vec::new(&env)));
+    assert_eq!(client.get_schema_version(), CURRENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_skips_entries_already_in_new_layout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_v1_deployment(&env);
+    let creator = Address::generate(&env);
+    let token = Address::generate(&env);
+    // Giveaway 4 was created by the new code after `upgrade`, before `migrate`.
+    seed_active_giveaway_with_creator(&env, &contract_id, 4, &token, &creator);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::GiveawayCounter, &4u64);
+    });
+
+    assert!(client.migrate(&10, &Vec::new(&env), &Vec::new(&env)));
+
+    env.as_contract(&contract_id, || {
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Giveaway(4))
+            .unwrap();
+        assert_eq!(giveaway.creator, creator);
+        assert!(giveaway.eligibility.is_empty());
+    });
+}
+
+#[test]
+fn test_migrate_seeds_liabilities_from_v1_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_v1_deployment(&env);
+    let token = Address::generate(&env);
+    let claimed_winner = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        // Giveaway 1 is still open; giveaway 3 paid one of its two winners.
+        let mut open = giveaway_v1(&env, 1, 0);
+        open.token = token.clone();
+        env.storage().persistent().set(&DataKey::Giveaway(1), &open);
+        let mut claimable = giveaway_v1(&env, 3, 0);
+        claimable.token = token.clone();
+        claimable.status = GiveawayStatus::Claimable;
+        claimable.winner_count = 2;
+        claimable.winners = vec![&env, claimed_winner.clone(), Address::generate(&env)];
+        claimable.claimed_count = 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Giveaway(3), &claimable);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Claimed(3, claimed_winner), &true);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::CollectedFees(token.clone()), &7i128);
+        // A partial count from before tracking was complete is replaced.
+        env.storage()
+            .persistent()
+            .set(&DataKey::Liabilities(token.clone()), &1i128);
+    });
+    let creator = Address::generate(&env);
+    force_request_state(
+        &env,
+        &contract_id,
+        2,
+        &token,
+        &creator,
+        300,
+        HelpRequestStatus::Cancelled,
+    );
+
+    assert!(!client.migrate(&4, &Vec::new(&env), &Vec::new(&env)));
+    assert!(client.migrate(&10, &vec![&env, token.clone()], &vec![&env, 1u64, 2, 99]));
+
+    // 500 escrowed, 250 unclaimed, 300 donated and 7 in fees.
+    assert_eq!(liabilities(&env, &contract_id, &token), 1_057);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&DataKey::MigrationLiabilities));
+    });
+}
+
+#[test]
+fn test_migrate_moves_v7_allowlists_and_donors_into_chunks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(UpgradeContract, ());
+    let client = UpgradeContractClient::new(&env, &contract_id);
+    let token = Address::generate(&env);
+    seed_active_giveaway_with_creator(&env, &contract_id, 1, &token, &Address::generate(&env));
+    let members = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let legacy_donors = vec![&env, Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&contract_id, || {
+        let storage = env.storage();
+        storage
+            .instance()
+            .set(&DataKey::Admin, &Address::generate(&env));
+        storage.instance().set(&DataKey::SchemaVersion, &7u32);
+        storage.instance().set(&DataKey::GiveawayCounter, &1u64);
+        for member in members.iter() {
+            storage
+                .persistent()
+                .set(&DataKey::GiveawayAllowlist(1, member), &true);
+        }
+        storage
+            .persistent()
+            .set(&DataKey::GiveawayAllowlistMembers(1), &members);
+        storage
+            .persistent()
+            .set(&DataKey::Donors(5), &legacy_donors);
+    });
+
+    // One unit per member or donor moved, and one for the giveaway once its
+    // members are.
+    assert!(!client.migrate(&2, &Vec::new(&env), &vec![&env, 5u64]));
+    assert!(!client.migrate(&3, &Vec::new(&env), &vec![&env, 5u64]));
+    // The giveaways are done and are not walked again for the last donor.
+    env.as_contract(&contract_id, || {
+        let cursor: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MigrationCursor)
+            .unwrap();
+        assert_eq!(cursor, 2);
+    });
+    assert!(client.migrate(&1, &Vec::new(&env), &vec![&env, 5u64]));
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert_eq!(allowlist(1).range(&env, 0, 10), members);
+        for (index, member) in members.iter().enumerate() {
+            let position: u32 = storage.get(&DataKey::GiveawayAllowlist(1, member)).unwrap();
+            assert_eq!(position, index as u32);
+        }
+        assert!(!storage.has(&DataKey::GiveawayAllowlistMembers(1)));
+        assert_eq!(
+            crate::mutual_aid::donors(5).range(&env, 0, 10),
+            legacy_donors
+        );
+        assert!(!storage.has(&DataKey::Donors(5)));
+    });
+}
+
+#[test]
+fn test_migrate_keeps_v1_allowlist_flags_removable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_v1_deployment(&env);
+    let member = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::GiveawayAllowlist(1, member.clone()), &true);
+    });
+
+    assert!(client.migrate(&10, &Vec::new(&env), &Vec::new(&env)));
+
+    env.as_contract(&contract_id, || {
+        assert!(is_allowlisted(&env, 1, &member));
+        assert!(!add_allowlist_member(&env, 1, &member));
+        assert_eq!(allowlist(1).len(&env), 0);
+
+        assert!(remove_allowlist_member(&env, 1, &member));
+        assert!(!is_allowlisted(&env, 1, &member));
+        assert!(!remove_allowlist_member(&env, 1, &member));
+
+        assert!(add_allowlist_member(&env, 1, &member));
+        assert_eq!(allowlist(1).range(&env, 0, 10), vec![&env, member.clone()]);
+    });
+}
+
+/// `Giveaway` as stored by schema v4, before eligibility became a rule tree.
+#[derive(Clone)]
+#[contracttype]
+pub struct GiveawayV4 {
+    pub id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub title: String,
+    pub end_time: u64,
+    pub status: GiveawayStatus,
+    pub winner_count: u32,
+    pub winners: Vec<Address>,
+    pub verification_type: VerificationType,
+    pub min_reputation: u64,
+    pub selection_method: SelectionMethod,
+    pub claim_deadline: u64,
+    pub claimed_count: u32,
+}
+
+#[test]
+fn test_migrate_turns_v4_verification_into_rule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(UpgradeContract, ());
+    let client = UpgradeContractClient::new(&env, &contract_id);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &Address::generate(&env));
+        env.storage().instance().set(&DataKey::SchemaVersion, &4u32);
+        env.storage()
+            .instance()
+            .set(&DataKey::GiveawayCounter, &2u64);
+        for (id, verification_type) in [
+            (1u64, VerificationType::Reputation),
+            (2, VerificationType::None),
+        ] {
+            let giveaway = GiveawayV4 {
+                id,
+                creator: Address::generate(&env),
+                token: Address::generate(&env),
+                amount: 500,
+                title: String::from_str(&env, "v4 Giveaway"),
+                end_time: env.ledger().timestamp() + 3600,
+                status: GiveawayStatus::Active,
+                winner_count: 1,
+                winners: Vec::new(&env),
+                verification_type,
+                min_reputation: 12,
+                selection_method: SelectionMethod::Random,
+                claim_deadline: 0,
+                claimed_count: 0,
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::Giveaway(id), &giveaway);
+        }
+    });
+
+    assert!(client.migrate(&10, &Vec::new(&env), &Vec::new(&env)));
+
+    env.as_contract(&contract_id, || {
+        let giveaway = |id: u64| -> Giveaway {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Giveaway(id))
+                .unwrap()
+        };
+        assert_eq!(
+            giveaway(1).eligibility,
+            vec![&env, EligibilityRule::MinReputation(12)]
+        );
+        assert!(giveaway(2).eligibility.is_empty());
+        // Converted before the fee fields were backfilled.
+        assert_eq!(giveaway(2).fee_mode, FeeMode::WinnerPays);
+        assert_eq!(giveaway(2).fee_bps, DEFAULT_FEE_BPS);
+    });
+}
+
+#[test]
+fn test_migrate_moves_token_allowlist_out_of_instance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_v1_deployment(&env);
+    let listed = Address::generate(&env);
+    let delisted = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &2u32);
+        env.storage()
+            .instance()
+            .set(&DataKey::AllowedToken(listed.clone()), &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::AllowedToken(delisted.clone()), &false);
+    });
+
+    let tokens = vec![
+        &env,
+        listed.clone(),
+        delisted.clone(),
+        Address::generate(&env),
+    ];
+    assert!(client.migrate(&10, &tokens, &Vec::new(&env)));
+    assert_eq!(client.get_schema_version(), CURRENT_SCHEMA_VERSION);
+
+    env.as_contract(&contract_id, || {
+        for token in [&listed, &delisted] {
+            assert!(!env
+                .storage()
+                .instance()
+                .has(&DataKey::AllowedToken(token.clone())));
+        }
+        assert!(env
+            .storage()
+            .persistent()
+            .has(&DataKey::AllowedToken(listed.clone())));
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::AllowedToken(delisted.clone())));
+        let allowed: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AllowedTokens)
+            .unwrap();
+        assert_eq!(allowed, vec![&env, listed.clone()]);
+    });
+}
+
+#[test]
+fn test_fresh_deployment_starts_on_current_schema() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _client, _token) = setup_giveaway_contract(&env);
+    env.as_contract(&contract_id, || {
+        let version: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap();
+        assert_eq!(version, CURRENT_SCHEMA_VERSION);
+    });
+}
+
+#[test]
+fn test_migrate_moves_participants_into_chunks_across_calls() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(UpgradeContract, ());
+    let client = UpgradeContractClient::new(&env, &contract_id);
+    let mut participants = Vec::new(&env);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &Address::generate(&env));
+        env.storage().instance().set(&DataKey::SchemaVersion, &3u32);
+        env.storage()
+            .instance()
+            .set(&DataKey::GiveawayCounter, &1u64);
+
+        let mut giveaway = giveaway_v1(&env, 1, 0);
+        giveaway.participant_count = 70;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Giveaway(1), &giveaway);
+        for index in 0..70 {
+            let participant = Address::generate(&env);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ParticipantIndex(1, index), &participant);
+            participants.push_back(participant);
+        }
+    });
+
+    // The whole budget goes to the first participants; the giveaway itself
+    // is only charged once they are all moved.
+    assert!(!client.migrate(&50, &Vec::new(&env), &Vec::new(&env)));
+    env.as_contract(&contract_id, || {
+        assert_eq!(participant_count(&env, 1), 50);
+    });
+    assert!(client.migrate(&50, &Vec::new(&env), &Vec::new(&env)));
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(participant_count(&env, 1), 70);
+        assert_eq!(participant_chunk(&env, 1, 0).len(), PARTICIPANT_CHUNK_SIZE);
+        for (index, participant) in participants.iter().enumerate() {
+            assert_eq!(participant_at(&env, 1, index as u32), Some(participant));
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&DataKey::ParticipantIndex(1, index as u32)));
+        }
+
+        // The entry now decodes in the current layout.
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Giveaway(1))
+            .unwrap();
+        assert!(giveaway.eligibility.is_empty());
+    });
+}
+
+#[test]
+fn test_upgrade_requires_timelock_once_delay_set() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_v1_deployment(&env);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::TimelockDelay, &TEST_TIMELOCK_DELAY);
+    });
+
+    let new_wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    assert_eq!(
+        client.try_upgrade(&new_wasm_hash),
+        Err(Ok(contract_error(Error::TimelockRequired)))
+    );
+
+    let timelock_id = env.register(TimelockContract, ());
+    let timelock = TimelockContractClient::new(&env, &timelock_id);
+    env.as_contract(&timelock_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &Address::generate(&env));
+    });
+    let action_id = timelock.queue_action(&TimelockAction::Upgrade(new_wasm_hash.clone()));
+    assert_eq!(
+        timelock.get_queued_action(&action_id).unwrap().action,
+        TimelockAction::Upgrade(new_wasm_hash)
+    );
+}
+
+// ── storage TTL tests ─────────────────────────────────────────────────────────
+
+use crate::chunks::LIST_CHUNK_SIZE;
+use crate::ttl::{DAY_IN_LEDGERS, DEFAULT_TTL_EXTEND_TO, DEFAULT_TTL_THRESHOLD};
+use soroban_sdk::testutils::storage::Persistent as _;
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+fn persistent_ttl<K: IntoVal<Env, Val>>(env: &Env, contract_id: &Address, key: &K) -> u32 {
+    env.as_contract(contract_id, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_live_giveaway_outlives_default_entry_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1000);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1000,
+        &String::from_str(&env, "Long Giveaway"),
+        &(60 * 24 * 60 * 60),
+        &1,
+        &None,
+    );
+    assert_eq!(
+        persistent_ttl(&env, &contract_id, &DataKey::Giveaway(giveaway_id)),
+        DEFAULT_TTL_EXTEND_TO
+    );
+
+    // Well past the 4096-ledger minimum TTL a fresh entry would get.
+    advance_ledgers(&env, 10 * DAY_IN_LEDGERS);
+    let participant = Address::generate(&env);
+    client.enter_giveaway(&participant, &giveaway_id);
+    for key in [
+        DataKey::ParticipantChunk(giveaway_id, 0),
+        DataKey::ParticipantCount(giveaway_id),
+    ] {
+        assert_eq!(
+            persistent_ttl(&env, &contract_id, &key),
+            DEFAULT_TTL_EXTEND_TO
+        );
+    }
+}
+
+#[test]
+fn test_bump_giveaway_extends_every_item_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let other_member = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1000);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1000,
+        &String::from_str(&env, "Allowlisted Giveaway"),
+        &(90 * 24 * 60 * 60),
+        &1,
+        &Some(ParticipantVerification {
+            allowlist: vec![&env, participant.clone(), other_member.clone()],
+            rule: EligibilityRule::Allowlisted,
+            merkle_root: None,
+            permit_verifier: None,
+            hold_until_draw: false,
+        }),
+    );
+    client.enter_giveaway(&participant, &giveaway_id);
+
+    // Let every entry drift below the 30-day threshold.
+    advance_ledgers(&env, DEFAULT_TTL_EXTEND_TO - DEFAULT_TTL_THRESHOLD + 1);
+    let member_key = DataKey::GiveawayAllowlist(giveaway_id, other_member);
+    assert!(persistent_ttl(&env, &contract_id, &member_key) < DEFAULT_TTL_THRESHOLD);
+
+    assert!(client.bump_giveaway(&giveaway_id, &0, &1));
+
+    for key in [
+        DataKey::Giveaway(giveaway_id),
+        DataKey::ParticipantChunk(giveaway_id, 0),
+        DataKey::ParticipantCount(giveaway_id),
+        DataKey::HasEntered(giveaway_id, participant),
+        member_key,
+    ] {
+        assert_eq!(
+            persistent_ttl(&env, &contract_id, &key),
+            DEFAULT_TTL_EXTEND_TO
+        );
+    }
+    for key in [
+        EntryKey::AllowlistChunk(giveaway_id, 0),
+        EntryKey::AllowlistCount(giveaway_id),
+    ] {
+        assert_eq!(
+            persistent_ttl(&env, &contract_id, &key),
+            DEFAULT_TTL_EXTEND_TO
+        );
+    }
+}
+
+#[test]
+fn test_bump_request_extends_donations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(MutualAidContract, ());
+    let client = MutualAidContractClient::new(&env, &contract_id);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &1000);
+    let request_id = client.post_help_request(&Address::generate(&env), &1, &5000, &token);
+    client.donate(&donor, &request_id, &300);
+    client.donate(&donor, &request_id, &200);
+    // A second donor chunk, reached only by a later bump.
+    let mut late_donor = donor.clone();
+    for _ in 0..LIST_CHUNK_SIZE {
+        late_donor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&late_donor, &1);
+        client.donate(&late_donor, &request_id, &1);
+    }
+
+    advance_ledgers(&env, DEFAULT_TTL_EXTEND_TO - DEFAULT_TTL_THRESHOLD + 1);
+    let late_key = DataKey::Donation(request_id, late_donor.clone());
+    assert!(!client.bump_request(&request_id, &0, &1));
+    assert!(persistent_ttl(&env, &contract_id, &late_key) < DEFAULT_TTL_THRESHOLD);
+
+    for key in [
+        DataKey::HelpRequest(request_id),
+        DataKey::DonorCount(request_id),
+        DataKey::DonorChunk(request_id, 0),
+        DataKey::Donation(request_id, donor.clone()),
+    ] {
+        assert_eq!(
+            persistent_ttl(&env, &contract_id, &key),
+            DEFAULT_TTL_EXTEND_TO
+        );
+    }
+
+    assert!(client.bump_request(&request_id, &1, &1));
+    assert_eq!(
+        persistent_ttl(&env, &contract_id, &late_key),
+        DEFAULT_TTL_EXTEND_TO
+    );
+    env.as_contract(&contract_id, || {
+        let donors = crate::mutual_aid::donors(request_id);
+        assert_eq!(donors.len(&env), LIST_CHUNK_SIZE + 1);
+        assert_eq!(donors.range(&env, 0, 1), vec![&env, donor.clone()]);
+        assert_eq!(
+            donors.range(&env, LIST_CHUNK_SIZE, 5),
+            vec![&env, late_donor.clone()]
+        );
+    });
+
+    assert_eq!(
+        client.try_bump_request(&99, &0, &1),
+        Err(Ok(contract_error(Error::HelpRequestNotFound)))
+    );
+}
+
+#[test]
+fn test_admin_configures_ttl_thresholds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AdminContract, ());
+    let client = AdminContractClient::new(&env, &contract_id);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &Address::generate(&env));
+    });
+
+    assert_eq!(client.get_ttl_config().extend_to, DEFAULT_TTL_EXTEND_TO);
+    assert_eq!(
+        client.try_set_ttl_config(&(10 * DAY_IN_LEDGERS), &(5 * DAY_IN_LEDGERS)),
+        Err(Ok(contract_error(Error::InvalidTtlConfig)))
+    );
+    assert_eq!(
+        client.try_set_ttl_config(&DAY_IN_LEDGERS, &u32::MAX),
+        Err(Ok(contract_error(Error::InvalidTtlConfig)))
+    );
+
+    client.set_ttl_config(&(7 * DAY_IN_LEDGERS), &(14 * DAY_IN_LEDGERS));
+    let config = client.get_ttl_config();
+    assert_eq!(config.threshold, 7 * DAY_IN_LEDGERS);
+    assert_eq!(config.extend_to, 14 * DAY_IN_LEDGERS);
+}
+
+// ── merit leaderboard tests ───────────────────────────────────────────────────
+
+use soroban_sdk::testutils::EnvTestConfig;
+
+/// Create a merit giveaway with `winner_count` winners on `client`.
+fn create_merit_giveaway(
+    env: &Env,
+    client: &GiveawayContractClient,
+    token: &Address,
+    winner_count: u32,
+) -> (u64, Address) {
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&creator, &1_000_000);
+    let giveaway_id = client.create_giveaway_with_selection(
+        &creator,
+        token,
+        &1_000_000,
+        &String::from_str(env, "Merit Leaderboard"),
+        &60,
+        &winner_count,
+        &None,
+        &SelectionMethod::Merit,
+        &FeeMode::WinnerPays,
+    );
+    (giveaway_id, creator)
+}
+
+#[test]
+fn test_merit_ties_go_to_earlier_entrants() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let (giveaway_id, creator) = create_merit_giveaway(&env, &client, &token, 2);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    let top = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        for participant in [&first, &second, &third] {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Reputation(participant.clone()), &5u64);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reputation(top.clone()), &9u64);
+    });
+    for participant in [&first, &second, &third, &top] {
+        client.enter_giveaway(participant, &giveaway_id);
+    }
+
+    // Reputation gained after entry does not change the snapshot.
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reputation(third.clone()), &100u64);
+    });
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.finalize_merit_winners(&creator, &giveaway_id);
+
+    env.as_contract(&contract_id, || {
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Giveaway(giveaway_id))
+            .unwrap();
+        assert_eq!(giveaway.winners, vec![&env, top.clone(), first.clone()]);
+    });
+}
+
+/// Build a 10-winner merit giveaway that already has `participant_count - 1`
+/// entrants, then return the CPU cost of one more real entry and of
+/// finalization.
+///
+/// Only the state the merit path reads - the participant count, the last
+/// participant chunk and the leaderboard - is seeded. On-chain a transaction only loads the entries in
+/// its footprint, whereas the test host keeps every entry in one metered map
+/// and would charge each call for copying thousands of unrelated ones.
+fn merit_selection_costs(participant_count: u32) -> (u64, u64) {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let (giveaway_id, creator) = create_merit_giveaway(&env, &client, &token, 10);
+
+    let seeded = participant_count - 1;
+    env.as_contract(&contract_id, || {
+        let mut leaderboard = Vec::new(&env);
+        for rank in 0..10 {
+            leaderboard.push_back(MeritEntry {
+                participant: Address::generate(&env),
+                reputation: 96 - rank as u64,
+                entry_index: seeded - 10 + rank,
+            });
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::MeritLeaderboard(giveaway_id), &leaderboard);
+
+        let mut last_chunk = Vec::new(&env);
+        for _ in 0..seeded % PARTICIPANT_CHUNK_SIZE {
+            last_chunk.push_back(Address::generate(&env));
+        }
+        env.storage().persistent().set(
+            &DataKey::ParticipantChunk(giveaway_id, seeded / PARTICIPANT_CHUNK_SIZE),
+            &last_chunk,
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::ParticipantCount(giveaway_id), &seeded);
+    });
+
+    // The last entrant outranks everyone, so the leaderboard is rewritten.
+    let last = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reputation(last.clone()), &1_000u64);
+    });
+    env.cost_estimate().budget().reset_default();
+    client.enter_giveaway(&last, &giveaway_id);
+    let enter_cpu = env.cost_estimate().budget().cpu_instruction_cost();
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    env.cost_estimate().budget().reset_default();
+    let winner = client
+        .finalize_merit_winners(&creator, &giveaway_id)
+        .unwrap();
+    let finalize_cpu = env.cost_estimate().budget().cpu_instruction_cost();
+    assert_eq!(winner, last);
+
+    (enter_cpu, finalize_cpu)
+}
+
+/// Per-transaction CPU instruction limit on the Stellar network.
+const TX_CPU_INSTRUCTION_LIMIT: u64 = 100_000_000;
+
+#[test]
+fn test_merit_selection_budget_1k_participants() {
+    let (enter_cpu, finalize_cpu) = merit_selection_costs(1_000);
+    assert!(enter_cpu < TX_CPU_INSTRUCTION_LIMIT / 10);
+    assert!(finalize_cpu < TX_CPU_INSTRUCTION_LIMIT / 10);
+}
+
+#[test]
+fn test_merit_selection_budget_10k_participants() {
+    let (enter_cpu, finalize_cpu) = merit_selection_costs(10_000);
+    let (small_enter_cpu, small_finalize_cpu) = merit_selection_costs(1_000);
+    assert!(enter_cpu < TX_CPU_INSTRUCTION_LIMIT / 10);
+    assert!(finalize_cpu < TX_CPU_INSTRUCTION_LIMIT / 10);
+
+    // Neither cost grows with the number of participants.
+    assert!(enter_cpu < small_enter_cpu * 2);
+    assert!(finalize_cpu < small_finalize_cpu * 2);
+}
+
+// ── chunked selection tests ───────────────────────────────────────────────────
+
+/// Enter `count` fresh participants into `giveaway_id`, in order.
+fn enter_participants(
+    env: &Env,
+    client: &GiveawayContractClient,
+    giveaway_id: u64,
+    count: u32,
+) -> Vec<Address> {
+    let mut participants = Vec::new(env);
+    for _ in 0..count {
+        let participant = Address::generate(env);
+        client.enter_giveaway(&participant, &giveaway_id);
+        participants.push_back(participant);
+    }
+    participants
+}
+
+fn giveaway_winners(env: &Env, contract_id: &Address, giveaway_id: u64) -> Vec<Address> {
+    env.as_contract(contract_id, || {
+        let giveaway: Giveaway = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Giveaway(giveaway_id))
+            .unwrap();
+        assert_eq!(giveaway.status, GiveawayStatus::Claimable);
+        giveaway.winners
+    })
+}
+
+#[test]
+fn test_chunked_random_selection_across_batches() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000_000);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000_000,
+        &String::from_str(&env, "Viral Giveaway"),
+        &60,
+        &60,
+        &None,
+    );
+    let participants = enter_participants(&env, &client, giveaway_id, 70);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    client.begin_selection(&giveaway_id);
+    let state = client.get_selection_state(&giveaway_id).unwrap();
+    assert_eq!(state.total, 60);
+
+    // The giveaway is locked while selection is in progress.
+    assert_eq!(
+        client.try_pick_winner(&giveaway_id).err(),
+        Some(Ok(contract_error(Error::InvalidStatus)))
+    );
+    assert_eq!(
+        client.try_begin_selection(&giveaway_id).err(),
+        Some(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    let keeper = Address::generate(&env);
+    assert_eq!(
+        client.try_complete_selection(&keeper, &giveaway_id).err(),
+        Some(Ok(contract_error(Error::SelectionIncomplete)))
+    );
+    assert!(!client.continue_selection(&keeper, &giveaway_id));
+    assert!(client.continue_selection(&keeper, &giveaway_id));
+    assert_eq!(
+        client.try_continue_selection(&keeper, &giveaway_id).err(),
+        Some(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    let winner = client.complete_selection(&keeper, &giveaway_id).unwrap();
+    assert!(client.get_selection_state(&giveaway_id).is_none());
+
+    // Winners follow `pick_winner`'s draw order for the fixed seed.
+    let winners = giveaway_winners(&env, &contract_id, giveaway_id);
+    assert_eq!(winners.len(), 60);
+    assert_eq!(winner, winners.get(0).unwrap());
+    for (i, drawn) in winners.iter().enumerate() {
+        let index = (state.seed.wrapping_add(i as u64) % 70) as u32;
+        assert_eq!(drawn, participants.get(index).unwrap());
+    }
+
+    // 0.1% of the prize went to the keeper across three paid calls.
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&keeper), 1_000);
+    let first_winner = winners.get(0).unwrap();
+    client.claim_prize(&giveaway_id, &first_winner);
+    assert_eq!(token_client.balance(&first_winner), 16_484);
+}
+
+#[test]
+fn test_chunked_selection_rechecks_held_entries_in_its_batches() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let (client, _, _, gate_token, giveaway_id) =
+        setup_holders_giveaway(&env, 52, SelectionMethod::Random);
+    let entrants = enter_holders(&env, &client, &gate_token, giveaway_id, 60, 5);
+    let sellers = entrants.slice(0..5);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    client.begin_selection(&giveaway_id);
+    let keeper = Address::generate(&env);
+    assert!(!client.continue_selection(&keeper, &giveaway_id));
+    assert!(client.continue_selection(&keeper, &giveaway_id));
+
+    // The batches passed over the sellers they came across, once each.
+    let state = client.get_selection_state(&giveaway_id).unwrap();
+    assert_eq!(state.winners.len(), 52);
+    assert!(!state.passed_over.is_empty());
+    for (index, passed) in state.passed_over.iter().enumerate() {
+        assert!(sellers.contains(&passed));
+        assert_eq!(
+            state.passed_over.first_index_of(&passed),
+            Some(index as u32)
+        );
+    }
+
+    client.complete_selection(&keeper, &giveaway_id);
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.winners, state.winners);
+    for seller in sellers.iter() {
+        assert!(!giveaway.winners.contains(&seller));
+    }
+}
+
+#[test]
+fn test_chunked_merit_selection_ranks_legacy_giveaway_in_batches() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let (giveaway_id, _creator) = create_merit_giveaway(&env, &client, &token, 3);
+    let participants = enter_participants(&env, &client, giveaway_id, 60);
+
+    // A giveaway entered before the leaderboard existed: rank current
+    // reputations, 0 to 6 repeating, so entrants 6, 13 and 20 share the top.
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::MeritLeaderboard(giveaway_id));
+        for (index, participant) in participants.iter().enumerate() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Reputation(participant), &(index as u64 % 7));
+        }
+    });
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    client.begin_selection(&giveaway_id);
+    assert_eq!(client.get_selection_state(&giveaway_id).unwrap().total, 60);
+
+    let keeper = Address::generate(&env);
+    assert!(!client.continue_selection(&keeper, &giveaway_id));
+    assert!(client.continue_selection(&keeper, &giveaway_id));
+    client.complete_selection(&keeper, &giveaway_id);
+
+    assert_eq!(
+        giveaway_winners(&env, &contract_id, giveaway_id),
+        vec![
+            &env,
+            participants.get(6).unwrap(),
+            participants.get(13).unwrap(),
+            participants.get(20).unwrap(),
+        ]
+    );
+    assert_eq!(token::Client::new(&env, &token).balance(&keeper), 1_000);
+}
+
+#[test]
+fn test_chunked_selection_rejects_small_and_manual_giveaways() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_contract_id, client, token) = setup_giveaway_contract(&env);
+    let (merit_id, _creator) = create_merit_giveaway(&env, &client, &token, 1);
+    let participants = enter_participants(&env, &client, merit_id, 2);
+
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &2_000);
+    let manual_id = client.create_giveaway_with_selection(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(&env, "Manual"),
+        &60,
+        &1,
+        &None,
+        &SelectionMethod::Manual,
+        &FeeMode::WinnerPays,
+    );
+    client.enter_giveaway(&participants.get(0).unwrap(), &manual_id);
+    let random_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(&env, "Small"),
+        &60,
+        &1,
+        &None,
+    );
+    client.enter_giveaway(&participants.get(0).unwrap(), &random_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    assert_eq!(
+        client.try_begin_selection(&manual_id).err(),
+        Some(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    // One batch of draws, or a stored leaderboard, settles in a single call:
+    // no keeper reward is taken from these prizes.
+    assert_eq!(
+        client.try_begin_selection(&random_id).err(),
+        Some(Ok(contract_error(Error::SelectionTooSmall)))
+    );
+    assert_eq!(
+        client.try_begin_selection(&merit_id).err(),
+        Some(Ok(contract_error(Error::SelectionTooSmall)))
+    );
+    assert!(client.get_selection_state(&random_id).is_none());
+
+    let winner = client.pick_winner(&random_id).unwrap();
+    client.claim_prize(&random_id, &winner);
+    assert_eq!(token::Client::new(&env, &token).balance(&winner), 990);
+}
+
+// ── participant storage tests ─────────────────────────────────────────────────
+
+/// Ceilings on the average `enter_giveaway` cost per entry, over 128 entries
+/// into one giveaway. The schema v3 layout, with one `ParticipantIndex` entry
+/// per participant and a full `Giveaway` rewrite per entry, exceeded each.
+const MAX_ENTRY_FEE: i64 = 3_200_000;
+const MAX_ENTRY_RENT_LEDGER_BYTES: i64 = 320_000_000;
+const MAX_ENTRY_INSTRUCTIONS: i64 = 550_000;
+
+struct EntryCosts {
+    fee: i64,
+    rent_ledger_bytes: i64,
+    instructions: i64,
+    write_entries: u32,
+}
+
+/// Enter `count` participants into a fresh giveaway and sum the estimated
+/// resources and fee of each `enter_giveaway` call.
+fn entry_costs(count: u32) -> EntryCosts {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let (_contract_id, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000_000);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000_000,
+        &String::from_str(&env, "Community Giveaway: win a limited edition print"),
+        &60,
+        &3,
+        &None,
+    );
+
+    let mut costs = EntryCosts {
+        fee: 0,
+        rent_ledger_bytes: 0,
+        instructions: 0,
+        write_entries: 0,
+    };
+    for _ in 0..count {
+        client.enter_giveaway(&Address::generate(&env), &giveaway_id);
+        let resources = env.cost_estimate().resources();
+        costs.fee += env.cost_estimate().fee().total;
+        costs.rent_ledger_bytes += resources.persistent_rent_ledger_bytes;
+        costs.instructions += resources.instructions;
+        costs.write_entries += resources.write_entries;
+    }
+    costs
+}
+
+#[test]
+fn test_chunked_participants_cut_entry_cost() {
+    let costs = entry_costs(128);
+
+    // Appending to a chunk pays rent only on the added address, where v3
+    // created a fresh entry per participant. Chunks are rewritten whole, so
+    // more bytes are written, but those are priced far below the rent saved.
+    assert!(costs.rent_ledger_bytes < MAX_ENTRY_RENT_LEDGER_BYTES * 128);
+    assert!(costs.fee < MAX_ENTRY_FEE * 128);
+    assert!(costs.instructions < MAX_ENTRY_INSTRUCTIONS * 128);
+
+    // `HasEntered`, the chunk and the count, plus the auth nonce.
+    assert_eq!(costs.write_entries, 128 * 4);
+}
+
+#[test]
+fn test_participants_fill_chunks_in_entry_order() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let (giveaway_id, creator) = create_merit_giveaway(&env, &client, &token, 1);
+    let participants = enter_participants(&env, &client, giveaway_id, 65);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(participant_count(&env, giveaway_id), 65);
+        assert_eq!(
+            participant_chunk(&env, giveaway_id, 0).len(),
+            PARTICIPANT_CHUNK_SIZE
+        );
+        assert_eq!(
+            participant_chunk(&env, giveaway_id, 1),
+            vec![&env, participants.get(64).unwrap()]
+        );
+        for (index, participant) in participants.iter().enumerate() {
+            assert_eq!(
+                participant_at(&env, giveaway_id, index as u32),
+                Some(participant)
+            );
+        }
+        assert_eq!(participant_at(&env, giveaway_id, 65), None);
+    });
+
+    // Cancellation still sees the entrants without a count on `Giveaway`.
+    assert_eq!(
+        client.try_cancel_giveaway(&creator, &giveaway_id).err(),
+        Some(Ok(contract_error(Error::InvalidStatus)))
+    );
+}
+
+// ── pruning tests ─────────────────────────────────────────────────────────────
+
+use crate::ttl::PRUNE_BATCH_SIZE;
+use crate::types::{GiveawaySummary, HelpRequestSummary};
+
+#[test]
+fn test_prune_completed_giveaway_across_calls() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(&env, "Prunable Giveaway"),
+        &60,
+        &2,
+        &None,
+    );
+    let participants = enter_participants(&env, &client, giveaway_id, 70);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.pick_winner(&giveaway_id);
+    let winners = giveaway_winners(&env, &contract_id, giveaway_id);
+
+    // Winners may still claim, so nothing can be pruned yet.
+    assert_eq!(
+        client.try_prune_giveaway(&giveaway_id).err(),
+        Some(Ok(contract_error(Error::InvalidStatus)))
+    );
+    for winner in winners.iter() {
+        client.claim_prize(&giveaway_id, &winner);
+    }
+
+    // 70 participants and 2 claim records take two batches.
+    const { assert!(PRUNE_BATCH_SIZE < 72 && 2 * PRUNE_BATCH_SIZE >= 72) };
+    assert!(!client.prune_giveaway(&giveaway_id));
+    assert!(client.prune_giveaway(&giveaway_id));
+
+    let summary = client.get_giveaway_summary(&giveaway_id).unwrap();
+    assert_eq!(
+        summary,
+        GiveawaySummary {
+            id: giveaway_id,
+            creator: creator.clone(),
+            token: token.clone(),
+            amount: 1_000,
+            status: GiveawayStatus::Completed,
+            winners: winners.clone(),
+            participant_count: 70,
+            claimed_count: 2,
+            end_time: summary.end_time,
+            claim_deadline: summary.claim_deadline,
+            pruned_at: env.ledger().timestamp(),
+        }
+    );
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&DataKey::Giveaway(giveaway_id)));
+        assert!(!storage.has(&DataKey::ParticipantCount(giveaway_id)));
+        assert!(!storage.has(&DataKey::ParticipantChunk(giveaway_id, 0)));
+        assert!(!storage.has(&DataKey::ParticipantChunk(giveaway_id, 1)));
+        for participant in participants.iter() {
+            assert!(!storage.has(&DataKey::HasEntered(giveaway_id, participant)));
+        }
+        for winner in winners.iter() {
+            assert!(!storage.has(&DataKey::Claimed(giveaway_id, winner)));
+        }
+    });
+
+    assert_eq!(
+        client.try_prune_giveaway(&giveaway_id).err(),
+        Some(Ok(contract_error(Error::GiveawayNotFound)))
+    );
+}
+
+#[test]
+fn test_prune_cancelled_giveaway_removes_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(&env, "Cancelled Giveaway"),
+        &60,
+        &1,
+        &Some(ParticipantVerification {
+            allowlist: vec![&env, member.clone()],
+            rule: EligibilityRule::Allowlisted,
+            merkle_root: None,
+            permit_verifier: None,
+            hold_until_draw: false,
+        }),
+    );
+    client.cancel_giveaway(&creator, &giveaway_id);
+
+    assert!(client.prune_giveaway(&giveaway_id));
+    assert_eq!(
+        client.get_giveaway_summary(&giveaway_id).unwrap().status,
+        GiveawayStatus::Cancelled
+    );
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&DataKey::GiveawayAllowlist(giveaway_id, member.clone())));
+        assert!(!storage.has(&EntryKey::AllowlistChunk(giveaway_id, 0)));
+        assert!(!storage.has(&EntryKey::AllowlistCount(giveaway_id)));
+        assert!(!storage.has(&DataKey::Giveaway(giveaway_id)));
+    });
+}
+
+#[test]
+fn test_prune_closed_request_keeps_summary_and_reserves_id() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+
+    let contract_id = env.register(MutualAidContract, ());
+    let client = MutualAidContractClient::new(&env, &contract_id);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let creator = Address::generate(&env);
+    let request_id = client.post_help_request(&creator, &7, &450, &token);
+
+    let mut donors = Vec::new(&env);
+    for _ in 0..45 {
+        let donor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&donor, &10);
+        client.donate(&donor, &request_id, &10);
+        donors.push_back(donor);
+    }
+
+    // Only a closed request is final; a funded one can still be claimed.
+    assert_eq!(
+        client.try_prune_request(&request_id).err(),
+        Some(Ok(contract_error(Error::InvalidStatus)))
+    );
+    client.claim_help_request_funds(&creator, &request_id);
+
+    assert!(!client.prune_request(&request_id));
+    assert!(client.prune_request(&request_id));
+
+    let summary = client.get_request_summary(&request_id).unwrap();
+    assert_eq!(
+        summary,
+        HelpRequestSummary {
+            id: request_id,
+            creator: creator.clone(),
+            token: token.clone(),
+            goal: 450,
+            raised_amount: 450,
+            donor_count: 45,
+            created_at: summary.created_at,
+            pruned_at: env.ledger().timestamp(),
+        }
+    );
+    assert!(client.get_request(&request_id).is_none());
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&DataKey::DonorChunk(request_id, 0)));
+        assert!(!storage.has(&DataKey::DonorCount(request_id)));
+        assert!(!storage.has(&DataKey::HelpRequestClaimed(request_id)));
+        for donor in donors.iter() {
+            assert!(!storage.has(&DataKey::Donation(request_id, donor)));
+        }
+    });
+
+    assert_eq!(
+        client.try_post_help_request(&Address::generate(&env), &request_id, &100, &token),
+        Err(Ok(contract_error(Error::HelpRequestAlreadyExists)))
+    );
+}
+
+// ── eligibility rule tests ────────────────────────────────────────────────────
+
+use crate::eligibility::{MAX_RULE_DEPTH, MAX_RULE_NODES};
+use crate::types::EntryError;
+
+/// Register the giveaway contract, allow a prize token and try to create a
+/// giveaway gated by `rule`, with `allowlist` as its allowlist.
+fn try_create_gated_giveaway<'a>(
+    env: &'a Env,
+    allowlist: Vec<Address>,
+    rule: EligibilityRule,
+) -> (
+    Address,
+    GiveawayContractClient<'a>,
+    Result<u64, soroban_sdk::Error>,
+) {
+    let contract_id = env.register(GiveawayContract, ());
+    let client = GiveawayContractClient::new(env, &contract_id);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&creator, &1_000);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(token.clone()), &true);
+    });
+
+    let result = client
+        .try_create_giveaway(
+            &creator,
+            &token,
+            &1_000,
+            &String::from_str(env, "Gated Giveaway"),
+            &60,
+            &1,
+            &Some(ParticipantVerification {
+                allowlist,
+                rule,
+                merkle_root: None,
+                permit_verifier: None,
+                hold_until_draw: false,
+            }),
+        )
+        .map(|id| id.unwrap())
+        .map_err(|error| error.unwrap());
+    (contract_id, client, result)
+}
+
+fn entry_error(error: EntryError) -> soroban_sdk::Error {
+    soroban_sdk::Error::from_contract_error(error as u32)
+}
+
+fn give_profile(env: &Env, contract_id: &Address, user: &Address, username: &str) {
+    env.as_contract(contract_id, || {
+        ProfileContract::set_profile(
+            env.clone(),
+            user.clone(),
+            String::from_str(env, username),
+            String::from_str(env, "avatar"),
+        );
+    });
+}
+
+fn give_reputation(env: &Env, contract_id: &Address, user: &Address, reputation: u64) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reputation(user.clone()), &reputation);
+    });
+}
+
+#[test]
+fn test_eligibility_rule_allows_allowlist_or_combined_requirements() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let gate_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let listed = Address::generate(&env);
+    // allowlisted OR (reputation >= 10 AND profile AND holds >= 50 gate tokens)
+    let rule = EligibilityRule::Any(vec![
+        &env,
+        EligibilityRule::Allowlisted,
+        EligibilityRule::All(vec![
+            &env,
+            EligibilityRule::MinReputation(10),
+            EligibilityRule::HasProfile,
+            EligibilityRule::MinTokenBalance(gate_token.clone(), 50),
+        ]),
+    ]);
+    let (contract_id, client, result) =
+        try_create_gated_giveaway(&env, vec![&env, listed.clone()], rule);
+    let giveaway_id = result.unwrap();
+
+    client.enter_giveaway(&listed, &giveaway_id);
+
+    // With neither branch met, `Any` reports its first rule.
+    let qualified = Address::generate(&env);
+    give_reputation(&env, &contract_id, &qualified, 10);
+    give_profile(&env, &contract_id, &qualified, "qualified");
+    assert_eq!(
+        client.try_enter_giveaway(&qualified, &giveaway_id),
+        Err(Ok(entry_error(EntryError::NotAllowlisted)))
+    );
+
+    token::StellarAssetClient::new(&env, &gate_token).mint(&qualified, &50);
+    client.enter_giveaway(&qualified, &giveaway_id);
+    assert_eq!(
+        env.as_contract(&contract_id, || participant_count(&env, giveaway_id)),
+        2
+    );
+}
+
+#[test]
+fn test_each_failing_rule_reports_its_own_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let gate_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let rule = EligibilityRule::All(vec![
+        &env,
+        EligibilityRule::MinReputation(10),
+        EligibilityRule::HasProfile,
+        EligibilityRule::MinTokenBalance(gate_token.clone(), 50),
+    ]);
+    let (contract_id, client, result) = try_create_gated_giveaway(&env, Vec::new(&env), rule);
+    let giveaway_id = result.unwrap();
+    let participant = Address::generate(&env);
+
+    give_reputation(&env, &contract_id, &participant, 9);
+    assert_eq!(
+        client.try_enter_giveaway(&participant, &giveaway_id),
+        Err(Ok(entry_error(EntryError::InsufficientReputation)))
+    );
+
+    give_reputation(&env, &contract_id, &participant, 10);
+    assert_eq!(
+        client.try_enter_giveaway(&participant, &giveaway_id),
+        Err(Ok(entry_error(EntryError::ProfileRequired)))
+    );
+
+    give_profile(&env, &contract_id, &participant, "participant");
+    token::StellarAssetClient::new(&env, &gate_token).mint(&participant, &49);
+    assert_eq!(
+        client.try_enter_giveaway(&participant, &giveaway_id),
+        Err(Ok(entry_error(EntryError::InsufficientTokenBalance)))
+    );
+
+    token::StellarAssetClient::new(&env, &gate_token).mint(&participant, &1);
+    client.enter_giveaway(&participant, &giveaway_id);
+}
+
+#[test]
+fn test_create_giveaway_rejects_invalid_eligibility_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let mut too_deep = EligibilityRule::HasProfile;
+    for _ in 0..MAX_RULE_DEPTH {
+        too_deep = EligibilityRule::All(vec![&env, too_deep]);
+    }
+    let mut too_many = Vec::new(&env);
+    for _ in 0..MAX_RULE_NODES {
+        too_many.push_back(EligibilityRule::HasProfile);
+    }
+    let invalid = [
+        too_deep,
+        EligibilityRule::All(too_many),
+        EligibilityRule::Any(Vec::new(&env)),
+        EligibilityRule::MinTokenBalance(Address::generate(&env), 0),
+    ];
+    for rule in invalid {
+        let (_, _, result) = try_create_gated_giveaway(&env, Vec::new(&env), rule);
+        assert_eq!(result, Err(entry_error(EntryError::InvalidEligibilityRule)));
+    }
+
+    let mut deepest = EligibilityRule::HasProfile;
+    for _ in 1..MAX_RULE_DEPTH {
+        deepest = EligibilityRule::Any(vec![&env, deepest]);
+    }
+    let (_, _, result) = try_create_gated_giveaway(&env, Vec::new(&env), deepest);
+    assert!(result.is_ok());
+}
+
+/// A 1_000-unit giveaway with `winner_count` prizes for holders of at least
+/// 50 of a gating token, re-checked at the draw.
+fn setup_holders_giveaway(
+    env: &Env,
+    winner_count: u32,
+    method: SelectionMethod,
+) -> (GiveawayContractClient<'_>, Address, Address, Address, u64) {
+    let contract_id = env.register(GiveawayContract, ());
+    let client = GiveawayContractClient::new(env, &contract_id);
+    let prize_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let gate_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &prize_token).mint(&creator, &1_000);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(prize_token.clone()), &true);
+    });
+
+    let giveaway_id = client.create_giveaway_with_selection(
+        &creator,
+        &prize_token,
+        &1_000,
+        &String::from_str(env, "Holders Only"),
+        &60,
+        &winner_count,
+        &Some(ParticipantVerification {
+            allowlist: Vec::new(env),
+            rule: EligibilityRule::MinTokenBalance(gate_token.clone(), 50),
+            merkle_root: None,
+            permit_verifier: None,
+            hold_until_draw: true,
+        }),
+        &method,
+        &FeeMode::WinnerPays,
+    );
+
+    (client, creator, prize_token, gate_token, giveaway_id)
+}
+
+/// Enter `count` holders of the gating token; the first `sellers` of them
+/// then sell some of it.
+fn enter_holders(
+    env: &Env,
+    client: &GiveawayContractClient,
+    gate_token: &Address,
+    giveaway_id: u64,
+    count: u32,
+    sellers: u32,
+) -> Vec<Address> {
+    let mut holders = Vec::new(env);
+    for index in 0..count {
+        let holder = Address::generate(env);
+        token::StellarAssetClient::new(env, gate_token).mint(&holder, &50);
+        client.enter_giveaway(&holder, &giveaway_id);
+        if index < sellers {
+            token::Client::new(env, gate_token).transfer(&holder, Address::generate(env), &1);
+        }
+        holders.push_back(holder);
+    }
+    holders
+}
+
+#[test]
+fn test_hold_until_draw_drops_winners_who_sold_and_refunds_their_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, prize_token, gate_token, giveaway_id) =
+        setup_holders_giveaway(&env, 2, SelectionMethod::Random);
+    let entrants = enter_holders(&env, &client, &gate_token, giveaway_id, 2, 1);
+    let holder = entrants.get_unchecked(1);
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(client.pick_winner(&giveaway_id), Some(holder.clone()));
+
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.winners, vec![&env, holder.clone()]);
+    // The seller's slot is returned to the creator instead of left unclaimable.
+    assert_eq!(
+        token::Client::new(&env, &prize_token).balance(&creator),
+        500
+    );
+
+    client.claim_prize(&giveaway_id, &holder);
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.status, GiveawayStatus::Completed);
+}
+
+#[test]
+fn test_hold_until_draw_draws_past_entrants_who_sold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, prize_token, gate_token, giveaway_id) =
+        setup_holders_giveaway(&env, 2, SelectionMethod::Random);
+    let entrants = enter_holders(&env, &client, &gate_token, giveaway_id, 5, 3);
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    client.pick_winner(&giveaway_id);
+
+    // Both prizes go to the two entrants who kept their tokens.
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.winners.len(), 2);
+    for winner in giveaway.winners.iter() {
+        assert!(entrants.slice(3..).contains(&winner));
+    }
+    assert_eq!(token::Client::new(&env, &prize_token).balance(&creator), 0);
+}
+
+#[test]
+fn test_hold_until_draw_ranks_merit_runner_up_past_leader_who_sold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, _, gate_token, giveaway_id) =
+        setup_holders_giveaway(&env, 1, SelectionMethod::Merit);
+    let entrants = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+    env.as_contract(&client.address, || {
+        for (entrant, reputation) in entrants.iter().zip([30u64, 20]) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Reputation(entrant), &reputation);
+        }
+    });
+    let gate = token::Client::new(&env, &gate_token);
+    for entrant in entrants.iter() {
+        token::StellarAssetClient::new(&env, &gate_token).mint(&entrant, &50);
+        client.enter_giveaway(&entrant, &giveaway_id);
+    }
+    gate.transfer(&entrants.get_unchecked(0), Address::generate(&env), &1);
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(
+        client.finalize_merit_winners(&creator, &giveaway_id),
+        Some(entrants.get_unchecked(1))
+    );
+}
+
+#[test]
+fn test_hold_until_draw_refunds_creator_when_every_entrant_sold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, prize_token, gate_token, giveaway_id) =
+        setup_holders_giveaway(&env, 2, SelectionMethod::Random);
+    enter_holders(&env, &client, &gate_token, giveaway_id, 3, 3);
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(client.pick_winner(&giveaway_id), None);
+
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.status, GiveawayStatus::Cancelled);
+    assert!(giveaway.winners.is_empty());
+    assert_eq!(
+        token::Client::new(&env, &prize_token).balance(&creator),
+        1_000
+    );
+}
+
+#[test]
+fn test_hold_until_draw_rechecks_only_the_rule_that_admitted_each_entrant() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(GiveawayContract, ());
+    let client = GiveawayContractClient::new(&env, &contract_id);
+    let prize_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let gate_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &prize_token).mint(&creator, &1_000);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(prize_token.clone()), &true);
+    });
+
+    let member = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &prize_token,
+        &1_000,
+        &String::from_str(&env, "Members or Holders"),
+        &60,
+        &2,
+        &Some(ParticipantVerification {
+            allowlist: vec![&env, member.clone()],
+            rule: EligibilityRule::Any(vec![
+                &env,
+                EligibilityRule::Allowlisted,
+                EligibilityRule::MinTokenBalance(gate_token.clone(), 50),
+            ]),
+            merkle_root: None,
+            permit_verifier: None,
+            hold_until_draw: true,
+        }),
+    );
+
+    client.enter_giveaway(&member, &giveaway_id);
+    token::StellarAssetClient::new(&env, &gate_token).mint(&seller, &50);
+    client.enter_giveaway(&seller, &giveaway_id);
+    token::Client::new(&env, &gate_token).transfer(&seller, Address::generate(&env), &1);
+
+    // The seller got in on their balance, not the allowlist, so selling
+    // below the threshold drops them even though `Any` lists both.
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(client.pick_winner(&giveaway_id), Some(member.clone()));
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.winners, vec![&env, member.clone()]);
+    assert_eq!(
+        token::Client::new(&env, &prize_token).balance(&creator),
+        500
+    );
+
+    // The admission records go with the rest of the entries.
+    client.claim_prize(&giveaway_id, &member);
+    assert!(client.prune_giveaway(&giveaway_id));
+    env.as_contract(&contract_id, || {
+        for entrant in [member, seller] {
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&EntryKey::AdmittedBy(giveaway_id, entrant)));
+        }
+    });
+}
+
+// ── merkle allowlist tests ────────────────────────────────────────────────────
+
+use crate::eligibility::{merkle_leaf, MAX_MERKLE_PROOF_LEN};
+use crate::types::EntryCredentials;
+
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = soroban_sdk::Bytes::from(first.clone());
+    pair.append(&soroban_sdk::Bytes::from(second.clone()));
+    env.crypto().sha256(&pair).to_bytes()
+}
+
+/// Build the allowlist tree of `members` and return its root with the proof
+/// for `members[index]`. An odd node out is carried up unhashed.
+fn merkle_root_and_proof(
+    env: &Env,
+    members: &Vec<Address>,
+    index: u32,
+) -> (BytesN<32>, Vec<BytesN<32>>) {
+    let mut layer: Vec<BytesN<32>> = Vec::new(env);
+    for member in members.iter() {
+        layer.push_back(merkle_leaf(env, &member));
+    }
+    let mut position = index;
+    let mut proof = Vec::new(env);
+    while layer.len() > 1 {
+        if let Some(sibling) = layer.get(position ^ 1) {
+            proof.push_back(sibling);
+        }
+        let mut next = Vec::new(env);
+        for pair in 0..layer.len().div_ceil(2) {
+            let left = layer.get_unchecked(pair * 2);
+            match layer.get(pair * 2 + 1) {
+                Some(right) => next.push_back(hash_pair(env, &left, &right)),
+                None => next.push_back(left),
+            }
+        }
+        layer = next;
+        position /= 2;
+    }
+    (layer.get_unchecked(0), proof)
+}
+
+fn credentials(merkle_proof: Vec<BytesN<32>>) -> EntryCredentials {
+    EntryCredentials {
+        merkle_proof,
+        permit_signature: None,
+        permit_expiry: 0,
+        permit_nonce: 0,
+        answer_commitment: None,
+    }
+}
+
+/// Register the giveaway contract and create a giveaway gated by a Merkle
+/// allowlist with `root`. Returns the contract, its client, the creator and
+/// the giveaway id.
+fn setup_merkle_giveaway(
+    env: &Env,
+    root: BytesN<32>,
+) -> (Address, GiveawayContractClient<'_>, Address, u64) {
+    let contract_id = env.register(GiveawayContract, ());
+    let client = GiveawayContractClient::new(env, &contract_id);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&creator, &1_000);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(token.clone()), &true);
+    });
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(env, "Merkle Giveaway"),
+        &60,
+        &1,
+        &Some(ParticipantVerification {
+            allowlist: Vec::new(env),
+            rule: EligibilityRule::MerkleAllowlisted,
+            merkle_root: Some(root),
+            permit_verifier: None,
+            hold_until_draw: false,
+        }),
+    );
+    (contract_id, client, creator, giveaway_id)
+}
+
+#[test]
+fn test_merkle_allowlist_admits_members_with_valid_proofs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let mut members = Vec::new(&env);
+    for _ in 0..5 {
+        members.push_back(Address::generate(&env));
+    }
+    let (root, proof) = merkle_root_and_proof(&env, &members, 3);
+    let (contract_id, client, _, giveaway_id) = setup_merkle_giveaway(&env, root);
+    let member = members.get_unchecked(3);
+
+    // Creation stores the root only, however many members the tree has.
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::GiveawayAllowlist(giveaway_id, member.clone())));
+    });
+
+    assert_eq!(
+        client.try_enter_giveaway(&member, &giveaway_id),
+        Err(Ok(entry_error(EntryError::InvalidMerkleProof)))
+    );
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_enter_giveaway_with_credentials(
+            &outsider,
+            &giveaway_id,
+            &credentials(proof.clone())
+        ),
+        Err(Ok(entry_error(EntryError::InvalidMerkleProof)))
+    );
+
+    client.enter_giveaway_with_credentials(&member, &giveaway_id, &credentials(proof));
+    let (_, last_proof) = merkle_root_and_proof(&env, &members, 4);
+    client.enter_giveaway_with_credentials(
+        &members.get_unchecked(4),
+        &giveaway_id,
+        &credentials(last_proof),
+    );
+    assert_eq!(
+        env.as_contract(&contract_id, || participant_count(&env, giveaway_id)),
+        2
+    );
+}
+
+#[test]
+fn test_creator_rotates_merkle_root_while_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    let (first_root, early_proof) =
+        merkle_root_and_proof(&env, &vec![&env, early.clone(), Address::generate(&env)], 0);
+    let (contract_id, client, creator, giveaway_id) = setup_merkle_giveaway(&env, first_root);
+    client.enter_giveaway_with_credentials(&early, &giveaway_id, &credentials(early_proof));
+
+    // The new list drops `early`, who stays entered, and adds `late`.
+    let (second_root, late_proof) =
+        merkle_root_and_proof(&env, &vec![&env, late.clone(), Address::generate(&env)], 0);
+    assert_eq!(
+        client.try_set_allowlist_root(&Address::generate(&env), &giveaway_id, &second_root),
+        Err(Ok(contract_error(Error::NotCreator)))
+    );
+    client.set_allowlist_root(&creator, &giveaway_id, &second_root);
+    client.enter_giveaway_with_credentials(&late, &giveaway_id, &credentials(late_proof));
+    assert_eq!(
+        env.as_contract(&contract_id, || participant_count(&env, giveaway_id)),
+        2
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    client.pick_winner(&giveaway_id);
+    assert_eq!(
+        client.try_set_allowlist_root(&creator, &giveaway_id, &second_root),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+}
+
+/// A proof of `depth` arbitrary siblings for `participant` and the root it
+/// folds into, as for a tree far larger than any allowlist that could be
+/// written entry by entry.
+fn folded_proof(env: &Env, participant: &Address, depth: u32) -> (BytesN<32>, Vec<BytesN<32>>) {
+    let mut root = merkle_leaf(env, participant);
+    let mut proof = Vec::new(env);
+    for i in 0..depth {
+        let sibling = env
+            .crypto()
+            .sha256(&soroban_sdk::Bytes::from_array(env, &i.to_be_bytes()))
+            .to_bytes();
+        root = hash_pair(env, &root, &sibling);
+        proof.push_back(sibling);
+    }
+    (root, proof)
+}
+
+#[test]
+fn test_merkle_proof_length_is_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let participant = Address::generate(&env);
+
+    let (root, proof) = folded_proof(&env, &participant, MAX_MERKLE_PROOF_LEN + 1);
+    let (_, client, _, giveaway_id) = setup_merkle_giveaway(&env, root);
+    assert_eq!(
+        client.try_enter_giveaway_with_credentials(&participant, &giveaway_id, &credentials(proof)),
+        Err(Ok(entry_error(EntryError::InvalidMerkleProof)))
+    );
+
+    let (root, proof) = folded_proof(&env, &participant, MAX_MERKLE_PROOF_LEN);
+    let (_, client, _, giveaway_id) = setup_merkle_giveaway(&env, root);
+    client.enter_giveaway_with_credentials(&participant, &giveaway_id, &credentials(proof));
+}
+
+// ── signed permit tests ───────────────────────────────────────────────────────
+
+use crate::eligibility::MAX_PERMIT_LIFETIME_SECONDS;
+use crate::types::PermitPayload;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::storage::Temporary as _;
+use soroban_sdk::xdr::ToXdr;
+
+/// Sign a permit for `participant` to enter `giveaway_id` on `contract_id`,
+/// as the off-chain verifier holding `key` would.
+fn signed_credentials(
+    env: &Env,
+    key: &SigningKey,
+    contract_id: &Address,
+    giveaway_id: u64,
+    participant: &Address,
+    expiry: u64,
+    nonce: u64,
+) -> EntryCredentials {
+    let payload = PermitPayload {
+        contract: contract_id.clone(),
+        giveaway_id,
+        participant: participant.clone(),
+        expiry,
+        nonce,
+    }
+    .to_xdr(env);
+    let mut message = [0u8; 256];
+    let message = &mut message[..payload.len() as usize];
+    payload.copy_into_slice(message);
+
+    EntryCredentials {
+        merkle_proof: Vec::new(env),
+        permit_signature: Some(BytesN::from_array(env, &key.sign(message).to_bytes())),
+        permit_expiry: expiry,
+        permit_nonce: nonce,
+        answer_commitment: None,
+    }
+}
+
+/// Register the giveaway contract and create a giveaway that needs a permit
+/// signed by `key`. Returns the contract, its client and the giveaway id.
+fn setup_permit_giveaway<'a>(
+    env: &'a Env,
+    key: &SigningKey,
+) -> (Address, GiveawayContractClient<'a>, u64) {
+    let contract_id = env.register(GiveawayContract, ());
+    let client = GiveawayContractClient::new(env, &contract_id);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&creator, &1_000);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(token.clone()), &true);
+    });
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(env, "Social Tasks Giveaway"),
+        &3_600,
+        &1,
+        &Some(ParticipantVerification {
+            allowlist: Vec::new(env),
+            rule: EligibilityRule::SignedPermit,
+            merkle_root: None,
+            permit_verifier: Some(BytesN::from_array(env, &key.verifying_key().to_bytes())),
+            hold_until_draw: false,
+        }),
+    );
+    (contract_id, client, giveaway_id)
+}
+
+#[test]
+fn test_signed_permit_admits_participant_and_spends_nonce() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let (contract_id, client, giveaway_id) = setup_permit_giveaway(&env, &key);
+    let participant = Address::generate(&env);
+    let expiry = 1_600;
+
+    assert_eq!(
+        client.try_enter_giveaway(&participant, &giveaway_id),
+        Err(Ok(entry_error(EntryError::InvalidPermit)))
+    );
+
+    let permit = signed_credentials(
+        &env,
+        &key,
+        &contract_id,
+        giveaway_id,
+        &participant,
+        expiry,
+        1,
+    );
+    client.enter_giveaway_with_credentials(&participant, &giveaway_id, &permit);
+    assert_eq!(
+        env.as_contract(&contract_id, || participant_count(&env, giveaway_id)),
+        1
+    );
+
+    // The spent nonce outlives the permit even at one-second ledgers.
+    let nonce_ttl = env.as_contract(&contract_id, || {
+        env.storage()
+            .temporary()
+            .get_ttl(&EntryKey::PermitNonce(giveaway_id, 1))
+    });
+    assert!(nonce_ttl as u64 >= expiry - 1_000);
+
+    // A nonce is good for one entry, whoever it is signed for.
+    let other = Address::generate(&env);
+    let reused = signed_credentials(&env, &key, &contract_id, giveaway_id, &other, expiry, 1);
+    assert_eq!(
+        client.try_enter_giveaway_with_credentials(&other, &giveaway_id, &reused),
+        Err(Ok(entry_error(EntryError::PermitNonceUsed)))
+    );
+
+    let expired = signed_credentials(&env, &key, &contract_id, giveaway_id, &other, 999, 2);
+    assert_eq!(
+        client.try_enter_giveaway_with_credentials(&other, &giveaway_id, &expired),
+        Err(Ok(entry_error(EntryError::PermitExpired)))
+    );
+
+    let too_long = signed_credentials(
+        &env,
+        &key,
+        &contract_id,
+        giveaway_id,
+        &other,
+        1_000 + MAX_PERMIT_LIFETIME_SECONDS + 1,
+        3,
+    );
+    assert_eq!(
+        client.try_enter_giveaway_with_credentials(&other, &giveaway_id, &too_long),
+        Err(Ok(entry_error(EntryError::InvalidPermit)))
+    );
+}
+
+#[test]
+fn test_permit_is_bound_to_participant_and_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let (contract_id, client, giveaway_id) = setup_permit_giveaway(&env, &key);
+    let participant = Address::generate(&env);
+    let intruder = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 600;
+
+    // A permit issued to someone else does not verify for the intruder.
+    let stolen = signed_credentials(
+        &env,
+        &key,
+        &contract_id,
+        giveaway_id,
+        &participant,
+        expiry,
+        1,
+    );
+    assert!(client
+        .try_enter_giveaway_with_credentials(&intruder, &giveaway_id, &stolen)
+        .is_err());
+
+    // Nor does one signed by a key other than the giveaway's verifier.
+    let forged = signed_credentials(
+        &env,
+        &SigningKey::from_bytes(&[8u8; 32]),
+        &contract_id,
+        giveaway_id,
+        &intruder,
+        expiry,
+        2,
+    );
+    assert!(client
+        .try_enter_giveaway_with_credentials(&intruder, &giveaway_id, &forged)
+        .is_err());
+
+    client.enter_giveaway_with_credentials(&participant, &giveaway_id, &stolen);
+}
+
+// ── editable allowlist tests ──────────────────────────────────────────────────
+
+/// Register the giveaway contract and create an allowlist giveaway. Returns
+/// the contract, its client, the creator and the giveaway id.
+fn setup_allowlist_giveaway(
+    env: &Env,
+    allowlist: Vec<Address>,
+    hold_until_draw: bool,
+) -> (Address, GiveawayContractClient<'_>, Address, u64) {
+    let contract_id = env.register(GiveawayContract, ());
+    let client = GiveawayContractClient::new(env, &contract_id);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&creator, &1_000);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(token.clone()), &true);
+    });
+
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(env, "Allowlist Giveaway"),
+        &60,
+        &1,
+        &Some(ParticipantVerification {
+            allowlist,
+            rule: EligibilityRule::Allowlisted,
+            merkle_root: None,
+            permit_verifier: None,
+            hold_until_draw,
+        }),
+    );
+    (contract_id, client, creator, giveaway_id)
+}
+
+#[test]
+fn test_creator_edits_allowlist_while_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    let never = Address::generate(&env);
+    let (contract_id, client, creator, giveaway_id) =
+        setup_allowlist_giveaway(&env, vec![&env, early.clone()], false);
+
+    assert_eq!(
+        client.try_add_to_allowlist(
+            &Address::generate(&env),
+            &giveaway_id,
+            &vec![&env, late.clone()]
+        ),
+        Err(Ok(contract_error(Error::NotCreator)))
+    );
+    client.add_to_allowlist(
+        &creator,
+        &giveaway_id,
+        &vec![&env, late.clone(), early.clone()],
+    );
+    // `early` was already listed, so only `late` is announced.
+    let events = env.events().all();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.get_unchecked(0).0, contract_id);
+
+    assert!(client.is_allowlisted(&giveaway_id, &late));
+    assert_eq!(
+        client.get_allowlist(&giveaway_id, &0, &1),
+        vec![&env, early.clone()]
+    );
+    assert_eq!(
+        client.get_allowlist(&giveaway_id, &1, &5),
+        vec![&env, late.clone()]
+    );
+    assert!(client.get_allowlist(&giveaway_id, &5, &1).is_empty());
+    client.enter_giveaway(&late, &giveaway_id);
+
+    client.remove_from_allowlist(&creator, &giveaway_id, &vec![&env, early.clone(), never]);
+    assert!(!client.is_allowlisted(&giveaway_id, &early));
+    assert_eq!(
+        client.get_allowlist(&giveaway_id, &0, &10),
+        vec![&env, late.clone()]
+    );
+    assert_eq!(
+        client.try_enter_giveaway(&early, &giveaway_id),
+        Err(Ok(entry_error(EntryError::NotAllowlisted)))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(
+        client.try_add_to_allowlist(&creator, &giveaway_id, &vec![&env, early]),
+        Err(Ok(contract_error(Error::GiveawayEnded)))
+    );
+}
+
+#[test]
+fn test_removing_an_entrant_from_allowlist_keeps_their_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member = Address::generate(&env);
+    let (_, client, creator, giveaway_id) =
+        setup_allowlist_giveaway(&env, vec![&env, member.clone()], true);
+    client.enter_giveaway(&member, &giveaway_id);
+    client.remove_from_allowlist(&creator, &giveaway_id, &vec![&env, member.clone()]);
+
+    // Even with `hold_until_draw`, only an explicit disqualification drops them.
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(client.pick_winner(&giveaway_id), Some(member));
+}
+
+// ── disqualification tests ────────────────────────────────────────────────────
+
+use crate::types::Disqualification;
+
+/// Register the giveaway contract with `admin` and create a random
+/// giveaway with `winner_count` winners and three entrants.
+fn setup_disqualification_giveaway<'a>(
+    env: &'a Env,
+    admin: &Address,
+    winner_count: u32,
+) -> (
+    Address,
+    GiveawayContractClient<'a>,
+    Address,
+    u64,
+    Vec<Address>,
+) {
+    let (contract_id, client, token) = setup_giveaway_contract(env);
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    });
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&creator, &1_000);
+    let giveaway_id = client.create_giveaway(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(env, "Disqualification"),
+        &60,
+        &winner_count,
+        &None,
+    );
+    let participants = enter_participants(env, &client, giveaway_id, 3);
+    (contract_id, client, creator, giveaway_id, participants)
+}
+
+#[test]
+fn test_disqualified_entrants_are_skipped_by_the_draw() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (contract_id, client, creator, giveaway_id, participants) =
+        setup_disqualification_giveaway(&env, &admin, 1);
+    let first = participants.get_unchecked(0);
+    let second = participants.get_unchecked(1);
+    let third = participants.get_unchecked(2);
+
+    assert_eq!(
+        client.try_disqualify_participant(&first, &giveaway_id, &second, &7),
+        Err(Ok(contract_error(Error::NotCreator)))
+    );
+    assert_eq!(
+        client.try_disqualify_participant(&creator, &giveaway_id, &Address::generate(&env), &7),
+        Err(Ok(entry_error(EntryError::NotEntered)))
+    );
+
+    client.disqualify_participant(&creator, &giveaway_id, &first, &7);
+    client.disqualify_participant(&admin, &giveaway_id, &third, &9);
+    let events = env.events().all();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.get_unchecked(0).0, contract_id);
+    assert_eq!(
+        client.try_disqualify_participant(&creator, &giveaway_id, &first, &7),
+        Err(Ok(entry_error(EntryError::AlreadyDisqualified)))
+    );
+    assert_eq!(
+        client.get_disqualification(&giveaway_id, &first),
+        Some(Disqualification {
+            reason_code: 7,
+            appealed: false,
+        })
+    );
+    assert_eq!(client.get_disqualification(&giveaway_id, &second), None);
+    // Entries are kept; only the draw passes over them.
+    assert_eq!(
+        env.as_contract(&contract_id, || participant_count(&env, giveaway_id)),
+        3
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(client.pick_winner(&giveaway_id), Some(second));
+}
+
+#[test]
+fn test_draw_needs_enough_eligible_entrants() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, creator, giveaway_id, participants) =
+        setup_disqualification_giveaway(&env, &Address::generate(&env), 3);
+    client.disqualify_participant(&creator, &giveaway_id, &participants.get_unchecked(1), &1);
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(
+        client.try_pick_winner(&giveaway_id),
+        Err(Ok(contract_error(Error::InsufficientParticipants)))
+    );
+}
+
+#[test]
+fn test_disqualifying_a_merit_leader_promotes_the_runner_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let (giveaway_id, creator) = create_merit_giveaway(&env, &client, &token, 1);
+    let leader = Address::generate(&env);
+    let runner_up = Address::generate(&env);
+    let late = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        for (participant, reputation) in [(&leader, 9u64), (&runner_up, 5), (&late, 3)] {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Reputation(participant.clone()), &reputation);
+        }
+    });
+    client.enter_giveaway(&leader, &giveaway_id);
+    client.enter_giveaway(&runner_up, &giveaway_id);
+
+    client.disqualify_participant(&creator, &giveaway_id, &leader, &3);
+    client.enter_giveaway(&late, &giveaway_id);
+    // Only the leader's entry goes; the runners-up stay ranked.
+    env.as_contract(&contract_id, || {
+        let leaderboard: Vec<MeritEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MeritLeaderboard(giveaway_id))
+            .unwrap();
+        assert_eq!(leaderboard.len(), 2);
+        assert_eq!(leaderboard.get_unchecked(0).participant, runner_up);
+        assert_eq!(leaderboard.get_unchecked(1).participant, late);
+    });
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.finalize_merit_winners(&creator, &giveaway_id),
+        Some(runner_up)
+    );
+}
+
+#[test]
+fn test_manual_winners_reject_disqualified_entrants() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+    let giveaway_id = client.create_giveaway_with_selection(
+        &creator,
+        &token,
+        &1_000,
+        &String::from_str(&env, "Manual"),
+        &60,
+        &1,
+        &None,
+        &SelectionMethod::Manual,
+        &FeeMode::WinnerPays,
+    );
+    let participants = enter_participants(&env, &client, giveaway_id, 2);
+    let cheater = participants.get_unchecked(0);
+    client.disqualify_participant(&creator, &giveaway_id, &cheater, &2);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.try_finalize_manual_winners(&creator, &giveaway_id, &vec![&env, cheater]),
+        Err(Ok(entry_error(EntryError::ParticipantDisqualified)))
+    );
+    let honest = participants.get_unchecked(1);
+    assert_eq!(
+        client.finalize_manual_winners(&creator, &giveaway_id, &vec![&env, honest.clone()]),
+        honest
+    );
+}
+
+#[test]
+fn test_moderator_reinstates_appealed_disqualification() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (contract_id, client, creator, giveaway_id, participants) =
+        setup_disqualification_giveaway(&env, &admin, 3);
+    let appellant = participants.get_unchecked(2);
+    let moderator = Address::generate(&env);
+    client.disqualify_participant(&creator, &giveaway_id, &appellant, &4);
+
+    env.as_contract(&contract_id, || {
+        AdminContract::grant_role(env.clone(), Role::Moderator, moderator.clone());
+        // Nothing to resolve until the entrant appeals.
+        assert_eq!(
+            GovernanceContract::appeal_disqualification(
+                env.clone(),
+                participants.get_unchecked(0),
+                giveaway_id
+            ),
+            Err(Error::InvalidStatus)
+        );
+        GovernanceContract::appeal_disqualification(env.clone(), appellant.clone(), giveaway_id)
+            .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GovernanceContract::appeal_disqualification(
+                env.clone(),
+                appellant.clone(),
+                giveaway_id
+            ),
+            Err(Error::InvalidStatus)
+        );
+    });
+    assert_eq!(
+        client.get_disqualification(&giveaway_id, &appellant),
+        Some(Disqualification {
+            reason_code: 4,
+            appealed: true,
+        })
+    );
+
+    env.as_contract(&contract_id, || {
+        AdminContract::resolve_disqualification_appeal(
+            env.clone(),
+            moderator.clone(),
+            giveaway_id,
+            appellant.clone(),
+            true,
+        );
+    });
+    assert_eq!(client.get_disqualification(&giveaway_id, &appellant), None);
+
+    // With all three eligible again, the three-winner draw can go ahead.
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    client.pick_winner(&giveaway_id);
+    assert!(giveaway_winners(&env, &contract_id, giveaway_id).contains(&appellant));
+    assert_eq!(
+        client.try_disqualify_participant(&creator, &giveaway_id, &appellant, &4),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+}
+
+// ── leave giveaway tests ──────────────────────────────────────────────────────
+
+use crate::giveaway::MERIT_LEADERBOARD_BUFFER;
+use crate::participants::entrant_count;
+use crate::types::EntryKey;
+
+#[test]
+fn test_leaving_empties_the_slot_for_the_draw() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, creator, giveaway_id, participants) =
+        setup_disqualification_giveaway(&env, &Address::generate(&env), 2);
+    let leaver = participants.get_unchecked(1);
+
+    assert_eq!(
+        client.try_leave_giveaway(&Address::generate(&env), &giveaway_id),
+        Err(Ok(entry_error(EntryError::NotEntered)))
+    );
+    client.leave_giveaway(&leaver, &giveaway_id);
+    let events = env.events().all();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.get_unchecked(0).0, contract_id);
+
+    assert_eq!(
+        client.try_leave_giveaway(&leaver, &giveaway_id),
+        Err(Ok(entry_error(EntryError::NotEntered)))
+    );
+    assert_eq!(
+        client.try_disqualify_participant(&creator, &giveaway_id, &leaver, &1),
+        Err(Ok(entry_error(EntryError::NotEntered)))
+    );
+    env.as_contract(&contract_id, || {
+        assert_eq!(participant_count(&env, giveaway_id), 3);
+        assert_eq!(entrant_count(&env, giveaway_id), 2);
+    });
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(
+        client.try_leave_giveaway(&participants.get_unchecked(0), &giveaway_id),
+        Err(Ok(contract_error(Error::GiveawayEnded)))
+    );
+    client.pick_winner(&giveaway_id);
+    let winners = giveaway_winners(&env, &contract_id, giveaway_id);
+    assert_eq!(winners.len(), 2);
+    assert!(!winners.contains(&leaver));
+}
+
+#[test]
+fn test_giveaway_can_be_cancelled_once_everyone_left() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, creator, giveaway_id, participants) =
+        setup_disqualification_giveaway(&env, &Address::generate(&env), 1);
+    let disqualified = participants.get_unchecked(2);
+    client.disqualify_participant(&creator, &giveaway_id, &disqualified, &1);
+    assert_eq!(
+        client.try_leave_giveaway(&disqualified, &giveaway_id),
+        Err(Ok(entry_error(EntryError::ParticipantDisqualified)))
+    );
+    client.leave_giveaway(&participants.get_unchecked(0), &giveaway_id);
+    client.leave_giveaway(&participants.get_unchecked(1), &giveaway_id);
+
+    // A disqualified entrant still holds an entry.
+    assert_eq!(
+        client.try_cancel_giveaway(&creator, &giveaway_id),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+    env.as_contract(&contract_id, || {
+        AdminContract::grant_role(env.clone(), Role::Moderator, creator.clone());
+    });
+    env.as_contract(&contract_id, || {
+        GovernanceContract::appeal_disqualification(env.clone(), disqualified.clone(), giveaway_id)
+            .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        AdminContract::resolve_disqualification_appeal(
+            env.clone(),
+            creator.clone(),
+            giveaway_id,
+            disqualified.clone(),
+            true,
+        );
+    });
+    client.leave_giveaway(&disqualified, &giveaway_id);
+
+    let token = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get::<_, Giveaway>(&DataKey::Giveaway(giveaway_id))
+            .unwrap()
+            .token
+    });
+    client.cancel_giveaway(&creator, &giveaway_id);
+    assert_eq!(token::Client::new(&env, &token).balance(&creator), 1_000);
+
+    assert!(client.prune_giveaway(&giveaway_id));
+    assert_eq!(
+        client
+            .get_giveaway_summary(&giveaway_id)
+            .unwrap()
+            .participant_count,
+        0
+    );
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&EntryKey::WithdrawnCount(giveaway_id)));
+        assert!(!storage.has(&DataKey::HasEntered(giveaway_id, disqualified.clone())));
+    });
+}
+
+#[test]
+fn test_entering_again_takes_the_slot_back_except_for_instant_prizes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _creator, giveaway_id, participants) =
+        setup_disqualification_giveaway(&env, &Address::generate(&env), 3);
+    let leaver = participants.get_unchecked(1);
+    client.leave_giveaway(&leaver, &giveaway_id);
+    client.enter_giveaway(&leaver, &giveaway_id);
+    assert_eq!(
+        client.try_enter_giveaway(&leaver, &giveaway_id),
+        Err(Ok(contract_error(Error::AlreadyEntered)))
+    );
+    env.as_contract(&contract_id, || {
+        assert_eq!(participant_count(&env, giveaway_id), 3);
+        assert_eq!(entrant_count(&env, giveaway_id), 3);
+    });
+
+    // Every slot is live again, so all three entrants win.
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    client.pick_winner(&giveaway_id);
+    assert!(giveaway_winners(&env, &contract_id, giveaway_id).contains(&leaver));
+
+    // Leaving a first-come giveaway gives up the prize for good, so it
+    // cannot be won again by coming back.
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedToken(token.clone()), &true);
+    });
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+    let schedule = GiveawaySchedule {
+        start_time: None,
+        duration_seconds: 60,
+        max_participants: None,
+        min_participants: 0,
+    };
+    let first_come_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::FirstCome,
+        &schedule,
+    )
+    .unwrap();
+    client.enter_giveaway(&leaver, &first_come_id);
+    client.leave_giveaway(&leaver, &first_come_id);
+    assert_eq!(
+        client.try_enter_giveaway(&leaver, &first_come_id),
+        Err(Ok(entry_error(EntryError::AlreadyLeft)))
+    );
+}
+
+#[test]
+fn test_merit_entrant_coming_back_keeps_their_slot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let (giveaway_id, creator) = create_merit_giveaway(&env, &client, &token, 1);
+    // Equal reputations, so the earlier slot wins the tie.
+    let participants = enter_participants(&env, &client, giveaway_id, 2);
+    let first = participants.get_unchecked(0);
+    client.leave_giveaway(&first, &giveaway_id);
+    client.enter_giveaway(&first, &giveaway_id);
+
+    let leaderboard: Vec<MeritEntry> = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MeritLeaderboard(giveaway_id))
+            .unwrap()
+    });
+    assert_eq!(leaderboard.get_unchecked(0).participant, first);
+    assert_eq!(leaderboard.get_unchecked(0).entry_index, 0);
+    assert_eq!(leaderboard.get_unchecked(1).entry_index, 1);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.finalize_merit_winners(&creator, &giveaway_id),
+        Some(first)
+    );
+}
+
+#[test]
+fn test_merit_leaders_leaving_past_the_buffer_ranks_everyone_again() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let (giveaway_id, creator) = create_merit_giveaway(&env, &client, &token, 1);
+    // One entrant more than the leaderboard holds, best first.
+    let entrants = MERIT_LEADERBOARD_BUFFER + 2;
+    let participants = Vec::from_iter(&env, (0..entrants).map(|_| Address::generate(&env)));
+    env.as_contract(&contract_id, || {
+        for (rank, participant) in participants.iter().enumerate() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Reputation(participant), &(100 - rank as u64));
+        }
+    });
+    for participant in participants.iter() {
+        client.enter_giveaway(&participant, &giveaway_id);
+    }
+    let leaderboard_len = || {
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .get::<_, Vec<MeritEntry>>(&DataKey::MeritLeaderboard(giveaway_id))
+                .map(|leaderboard| leaderboard.len())
+        })
+    };
+    assert_eq!(leaderboard_len(), Some(MERIT_LEADERBOARD_BUFFER + 1));
+
+    // Each leader who leaves hands the top spot to the next runner-up.
+    for leader in participants.iter().take(MERIT_LEADERBOARD_BUFFER as usize) {
+        client.leave_giveaway(&leader, &giveaway_id);
+    }
+    assert_eq!(leaderboard_len(), Some(1));
+
+    // With the buffer spent and an entrant left off it, the leaderboard goes
+    // and selection ranks the remaining entrants.
+    client.leave_giveaway(
+        &participants.get_unchecked(MERIT_LEADERBOARD_BUFFER),
+        &giveaway_id,
+    );
+    assert_eq!(leaderboard_len(), None);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.finalize_merit_winners(&creator, &giveaway_id),
+        Some(participants.get_unchecked(entrants - 1))
+    );
+}
+
+// ── schedule and reserve tests ────────────────────────────────────────────────
+
+use crate::types::GiveawaySchedule;
+
+/// Create a two-winner giveaway on `client` with `selection_method` and
+/// `schedule`.
+fn create_scheduled(
+    env: &Env,
+    client: &GiveawayContractClient,
+    token: &Address,
+    creator: &Address,
+    selection_method: SelectionMethod,
+    schedule: &GiveawaySchedule,
+) -> Result<u64, soroban_sdk::Error> {
+    client
+        .try_create_scheduled_giveaway(
+            creator,
+            token,
+            &1_000,
+            &String::from_str(env, "Scheduled"),
+            &2,
+            &None,
+            &selection_method,
+            &FeeMode::WinnerPays,
+            schedule,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|error| error.unwrap())
+}
+
+#[test]
+fn test_scheduled_giveaway_opens_late_and_caps_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+    let now = env.ledger().timestamp();
+    let mut schedule = GiveawaySchedule {
+        start_time: Some(now + 100),
+        duration_seconds: 60,
+        max_participants: Some(1),
+        min_participants: 0,
+    };
+    assert_eq!(
+        create_scheduled(
+            &env,
+            &client,
+            &token,
+            &creator,
+            SelectionMethod::Random,
+            &schedule
+        ),
+        Err(entry_error(EntryError::InvalidSchedule))
+    );
+    schedule.max_participants = Some(2);
+    let giveaway_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::Random,
+        &schedule,
+    )
+    .unwrap();
+
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.start_time, now + 100);
+    assert_eq!(giveaway.end_time, now + 160);
+    assert_eq!(giveaway.max_participants, Some(2));
+
+    let participants = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    assert_eq!(
+        client.try_enter_giveaway(&participants[0], &giveaway_id),
+        Err(Ok(entry_error(EntryError::EntriesNotOpen)))
+    );
+    env.ledger().with_mut(|l| l.timestamp = now + 100);
+    client.enter_giveaway(&participants[0], &giveaway_id);
+    client.enter_giveaway(&participants[1], &giveaway_id);
+    assert_eq!(
+        client.try_enter_giveaway(&participants[2], &giveaway_id),
+        Err(Ok(entry_error(EntryError::GiveawayFull)))
+    );
+
+    // Leaving frees the spot.
+    client.leave_giveaway(&participants[1], &giveaway_id);
+    client.enter_giveaway(&participants[2], &giveaway_id);
+    assert_eq!(client.get_entrant_count(&giveaway_id), 2);
+}
+
+#[test]
+fn test_unmet_reserve_refunds_creator_instead_of_drawing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &2_000);
+    let schedule = GiveawaySchedule {
+        start_time: None,
+        duration_seconds: 60,
+        max_participants: None,
+        min_participants: 3,
+    };
+    let short_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::Random,
+        &schedule,
+    )
+    .unwrap();
+    let met_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::Random,
+        &schedule,
+    )
+    .unwrap();
+    enter_participants(&env, &client, short_id, 2);
+    enter_participants(&env, &client, met_id, 3);
+
+    assert_eq!(
+        client.try_settle_unmet_reserve(&short_id),
+        Err(Ok(contract_error(Error::GiveawayStillActive)))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(
+        client.try_pick_winner(&short_id),
+        Err(Ok(entry_error(EntryError::ReserveNotMet)))
+    );
+    assert_eq!(
+        client.try_settle_unmet_reserve(&met_id),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    client.settle_unmet_reserve(&short_id);
+    let events = env.events().all();
+    assert_eq!(events.last_unchecked().0, contract_id);
+    assert_eq!(token_client.balance(&creator), 1_000);
+    assert_eq!(
+        client.get_giveaway(&short_id).unwrap().status,
+        GiveawayStatus::Cancelled
+    );
+    assert_eq!(
+        client.try_settle_unmet_reserve(&short_id),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    client.pick_winner(&met_id);
+    assert_eq!(giveaway_winners(&env, &contract_id, met_id).len(), 2);
+}
+
+#[test]
+fn test_refunded_giveaways_return_the_creator_pays_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &2_020);
+    let schedule = GiveawaySchedule {
+        start_time: None,
+        duration_seconds: 60,
+        max_participants: None,
+        min_participants: 2,
+    };
+    let create = || {
+        client.create_scheduled_giveaway(
+            &creator,
+            &token,
+            &1_000,
+            &String::from_str(&env, "Creator Pays"),
+            &1,
+            &None,
+            &SelectionMethod::Random,
+            &FeeMode::CreatorPays,
+            &schedule,
+        )
+    };
+    let cancelled_id = create();
+    let short_id = create();
+    assert_eq!(token_client.balance(&creator), 0);
+
+    client.cancel_giveaway(&creator, &cancelled_id);
+    assert_eq!(token_client.balance(&creator), 1_010);
+
+    enter_participants(&env, &client, short_id, 1);
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    client.settle_unmet_reserve(&short_id);
+    assert_eq!(token_client.balance(&creator), 2_020);
+
+    // Neither fee was earned, so nothing is left to collect or owe.
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::CollectedFees(token.clone())));
+    });
+    assert_eq!(liabilities(&env, &contract_id, &token), 0);
+}
+
+// ── first-come and instant-win tests ──────────────────────────────────────────
+
+#[test]
+fn test_first_come_entrants_win_on_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+    let schedule = GiveawaySchedule {
+        start_time: None,
+        duration_seconds: 60,
+        max_participants: None,
+        min_participants: 0,
+    };
+    let giveaway_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::FirstCome,
+        &schedule,
+    )
+    .unwrap();
+
+    let [bot, second, third, late] = [0; 4].map(|_| Address::generate(&env));
+    client.enter_giveaway(&bot, &giveaway_id);
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.status, GiveawayStatus::Active);
+    assert_eq!(giveaway.winners, vec![&env, bot.clone()]);
+
+    // A disqualified winner's prize goes back into play.
+    client.disqualify_participant(&creator, &giveaway_id, &bot, &1);
+    assert!(client
+        .get_giveaway(&giveaway_id)
+        .unwrap()
+        .winners
+        .is_empty());
+    client.enter_giveaway(&second, &giveaway_id);
+    client.enter_giveaway(&third, &giveaway_id);
+
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.status, GiveawayStatus::Claimable);
+    assert_eq!(giveaway.winners, vec![&env, second.clone(), third]);
+    assert_eq!(
+        client.try_enter_giveaway(&late, &giveaway_id),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    client.claim_prize(&giveaway_id, &second);
+    assert_eq!(token_client.balance(&second), 495);
+}
+
+#[test]
+fn test_instant_win_hands_out_every_prize_by_the_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &1_000);
+    let mut schedule = GiveawaySchedule {
+        start_time: None,
+        duration_seconds: 60,
+        max_participants: None,
+        min_participants: 0,
+    };
+    assert_eq!(
+        create_scheduled(
+            &env,
+            &client,
+            &token,
+            &creator,
+            SelectionMethod::InstantWin,
+            &schedule
+        ),
+        Err(entry_error(EntryError::InvalidSchedule))
+    );
+    schedule.max_participants = Some(4);
+    let giveaway_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::InstantWin,
+        &schedule,
+    )
+    .unwrap();
+
+    // However the rolls fall, the fourth entry at the latest wins the last
+    // prize and closes the giveaway.
+    let mut participants = Vec::new(&env);
+    while client.get_giveaway(&giveaway_id).unwrap().status == GiveawayStatus::Active {
+        let participant = Address::generate(&env);
+        client.enter_giveaway(&participant, &giveaway_id);
+        participants.push_back(participant);
+    }
+    assert!(participants.len() <= 4);
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.status, GiveawayStatus::Claimable);
+    assert_eq!(giveaway.winners.len(), 2);
+    for winner in giveaway.winners.iter() {
+        assert!(participants.contains(&winner));
+    }
+}
+
+#[test]
+fn test_closing_unfilled_instant_giveaways() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&creator, &2_000);
+    let schedule = GiveawaySchedule {
+        start_time: None,
+        duration_seconds: 60,
+        max_participants: None,
+        min_participants: 0,
+    };
+    let partial_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::FirstCome,
+        &schedule,
+    )
+    .unwrap();
+    let empty_id = create_scheduled(
+        &env,
+        &client,
+        &token,
+        &creator,
+        SelectionMethod::FirstCome,
+        &schedule,
+    )
+    .unwrap();
+    let winner = enter_participants(&env, &client, partial_id, 1).get_unchecked(0);
+
+    assert_eq!(
+        client.try_close_instant_giveaway(&partial_id),
+        Err(Ok(contract_error(Error::GiveawayStillActive)))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(
+        client.try_pick_winner(&partial_id),
+        Err(Ok(contract_error(Error::InvalidStatus)))
+    );
+
+    client.close_instant_giveaway(&partial_id);
+    let giveaway = client.get_giveaway(&partial_id).unwrap();
+    assert_eq!(giveaway.status, GiveawayStatus::Claimable);
+    assert_eq!(giveaway.winners, vec![&env, winner]);
+    assert_eq!(token_client.balance(&creator), 500);
+
+    client.close_instant_giveaway(&empty_id);
+    assert_eq!(
+        client.get_giveaway(&empty_id).unwrap().status,
+        GiveawayStatus::Cancelled
+    );
+    assert_eq!(token_client.balance(&creator), 1_500);
+}
+
+// ── skill contest tests ───────────────────────────────────────────────────────
+
+use crate::contest::{answer_commitment, REVEAL_WINDOW_SECONDS};
+use crate::types::{ContestOrdering, SkillContest};
+use soroban_sdk::Bytes;
+
+/// The salt creators commit their contest answers with in these tests.
+fn creator_salt(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[1; 32])
+}
+
+/// Create a skill contest on `client` whose correct answer is `answer`,
+/// committed with `creator_salt`. Entries stay open for 60 seconds.
+fn create_skill_contest(
+    env: &Env,
+    client: &GiveawayContractClient,
+    token: &Address,
+    creator: &Address,
+    winner_count: u32,
+    ordering: ContestOrdering,
+    answer: &Bytes,
+) -> u64 {
+    token::StellarAssetClient::new(env, token).mint(creator, &1_000);
+    client.create_skill_contest(
+        creator,
+        token,
+        &1_000,
+        &String::from_str(env, "Trivia"),
+        &winner_count,
+        &None,
+        &FeeMode::WinnerPays,
+        &GiveawaySchedule {
+            start_time: None,
+            duration_seconds: 60,
+            max_participants: None,
+            min_participants: 0,
+        },
+        &SkillContest {
+            answer_hash: answer_commitment(env, creator, answer, &creator_salt(env)),
+            ordering,
+        },
+    )
+}
+
+/// Enter a fresh participant committed to `answer`. Returns the participant
+/// and the salt to reveal with.
+fn enter_with_answer(
+    env: &Env,
+    client: &GiveawayContractClient,
+    giveaway_id: u64,
+    answer: &Bytes,
+) -> (Address, BytesN<32>) {
+    let participant = Address::generate(env);
+    let salt = BytesN::from_array(env, &[7; 32]);
+    client.enter_giveaway_with_credentials(
+        &participant,
+        &giveaway_id,
+        &EntryCredentials {
+            merkle_proof: Vec::new(env),
+            permit_signature: None,
+            permit_expiry: 0,
+            permit_nonce: 0,
+            answer_commitment: Some(answer_commitment(env, &participant, answer, &salt)),
+        },
+    );
+    (participant, salt)
+}
+
+#[test]
+fn test_skill_contest_rewards_first_correct_revealers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    let right = Bytes::from_slice(&env, b"paris");
+    let wrong = Bytes::from_slice(&env, b"lyon");
+    let creator_salt = creator_salt(&env);
+    let giveaway_id = create_skill_contest(
+        &env,
+        &client,
+        &token,
+        &creator,
+        2,
+        ContestOrdering::RevealOrder,
+        &right,
+    );
+    // A contest needs its committed answer, which only create_skill_contest takes.
+    let schedule = GiveawaySchedule {
+        start_time: None,
+        duration_seconds: 60,
+        max_participants: None,
+        min_participants: 0,
+    };
+    assert_eq!(
+        create_scheduled(
+            &env,
+            &client,
+            &token,
+            &creator,
+            SelectionMethod::SkillContest,
+            &schedule
+        ),
+        Err(entry_error(EntryError::AnswerRequired))
+    );
+
+    assert_eq!(
+        client.try_enter_giveaway(&Address::generate(&env), &giveaway_id),
+        Err(Ok(entry_error(EntryError::AnswerRequired)))
+    );
+    let (first, first_salt) = enter_with_answer(&env, &client, giveaway_id, &right);
+    let (guesser, guesser_salt) = enter_with_answer(&env, &client, giveaway_id, &wrong);
+    let (second, second_salt) = enter_with_answer(&env, &client, giveaway_id, &right);
+    let (third, third_salt) = enter_with_answer(&env, &client, giveaway_id, &right);
+    let (sleeper, sleeper_salt) = enter_with_answer(&env, &client, giveaway_id, &right);
+
+    assert_eq!(
+        client.try_reveal_contest_answer(&creator, &giveaway_id, &right, &creator_salt),
+        Err(Ok(contract_error(Error::GiveawayStillActive)))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    assert_eq!(
+        client.try_reveal_answer(&second, &giveaway_id, &right, &second_salt),
+        Err(Ok(entry_error(EntryError::AnswerNotRevealed)))
+    );
+    assert_eq!(
+        client.try_reveal_contest_answer(&creator, &giveaway_id, &wrong, &creator_salt),
+        Err(Ok(entry_error(EntryError::AnswerMismatch)))
+    );
+    client.reveal_contest_answer(&creator, &giveaway_id, &right, &creator_salt);
+    assert_eq!(
+        client.get_contest_answer(&giveaway_id).unwrap().answer,
+        right
+    );
+
+    // Claiming the right answer after the fact does not match the commitment.
+    assert_eq!(
+        client.try_reveal_answer(&guesser, &giveaway_id, &right, &guesser_salt),
+        Err(Ok(entry_error(EntryError::AnswerMismatch)))
+    );
+    assert!(client.reveal_answer(&second, &giveaway_id, &right, &second_salt));
+    assert!(!client.reveal_answer(&guesser, &giveaway_id, &wrong, &guesser_salt));
+    assert!(client.reveal_answer(&first, &giveaway_id, &right, &first_salt));
+    assert!(client.reveal_answer(&third, &giveaway_id, &right, &third_salt));
+    assert_eq!(
+        client.try_reveal_answer(&first, &giveaway_id, &right, &first_salt),
+        Err(Ok(entry_error(EntryError::NotEntered)))
+    );
+
+    assert_eq!(
+        client.try_finalize_contest_winners(&giveaway_id),
+        Err(Ok(contract_error(Error::GiveawayStillActive)))
+    );
+    env.ledger()
+        .with_mut(|l| l.timestamp += REVEAL_WINDOW_SECONDS + 1);
+    assert_eq!(
+        client.try_reveal_answer(&sleeper, &giveaway_id, &right, &sleeper_salt),
+        Err(Ok(entry_error(EntryError::RevealClosed)))
+    );
+    client.finalize_contest_winners(&giveaway_id);
+
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.status, GiveawayStatus::Claimable);
+    assert_eq!(giveaway.winners, vec![&env, second, first]);
+}
+
+#[test]
+fn test_skill_contest_entrants_cannot_be_disqualified_while_revealing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let creator = Address::generate(&env);
+    let answer = Bytes::from_slice(&env, b"paris");
+    let giveaway_id = create_skill_contest(
+        &env,
+        &client,
+        &token,
+        &creator,
+        1,
+        ContestOrdering::RevealOrder,
+        &answer,
+    );
+    let (entrant, salt) = enter_with_answer(&env, &client, giveaway_id, &answer);
+
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    assert_eq!(
+        client.try_disqualify_participant(&creator, &giveaway_id, &entrant, &1),
+        Err(Ok(contract_error(Error::GiveawayEnded)))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    client.reveal_contest_answer(&creator, &giveaway_id, &answer, &creator_salt(&env));
+    assert!(client.reveal_answer(&entrant, &giveaway_id, &answer, &salt));
+    assert_eq!(
+        client.try_disqualify_participant(&creator, &giveaway_id, &entrant, &1),
+        Err(Ok(contract_error(Error::GiveawayEnded)))
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += REVEAL_WINDOW_SECONDS + 1);
+    client.finalize_contest_winners(&giveaway_id);
+    let giveaway = client.get_giveaway(&giveaway_id).unwrap();
+    assert_eq!(giveaway.winners, vec![&env, entrant]);
+}
+
+#[test]
+fn test_skill_contest_draws_among_correct_revealers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    let right = Bytes::from_slice(&env, b"42");
+    let creator_salt = creator_salt(&env);
+    let giveaway_id = create_skill_contest(
+        &env,
+        &client,
+        &token,
+        &creator,
+        3,
+        ContestOrdering::Random,
+        &right,
+    );
+    let mut correct = Vec::new(&env);
+    let mut reveals = Vec::new(&env);
+    for answer in [&right, &Bytes::from_slice(&env, b"41"), &right] {
+        let (participant, salt) = enter_with_answer(&env, &client, giveaway_id, answer);
+        if answer == &right {
+            correct.push_back(participant.clone());
+        }
+        reveals.push_back((participant, answer.clone(), salt));
+    }
+    let (cheater, cheater_salt) = enter_with_answer(&env, &client, giveaway_id, &right);
+    client.disqualify_participant(&creator, &giveaway_id, &cheater, &1);
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    client.reveal_contest_answer(&creator, &giveaway_id, &right, &creator_salt);
+    for (participant, answer, salt) in reveals.iter() {
+        client.reveal_answer(&participant, &giveaway_id, &answer, &salt);
+    }
+    assert!(!client.reveal_answer(&cheater, &giveaway_id, &right, &cheater_salt));
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += REVEAL_WINDOW_SECONDS + 1);
+    client.finalize_contest_winners(&giveaway_id);
+
+    // Two correct answers share a three-winner prize; the third share goes
+    // back to the creator.
+    let winners = client.get_giveaway(&giveaway_id).unwrap().winners;
+    assert_eq!(winners.len(), 2);
+    for winner in correct.iter() {
+        assert!(winners.contains(&winner));
+    }
+    assert_eq!(token_client.balance(&creator), 333);
+}
+
+#[test]
+fn test_skill_contest_settles_without_a_correct_answer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, token) = setup_giveaway_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let creator = Address::generate(&env);
+    let right = Bytes::from_slice(&env, b"blue");
+    let wrong = Bytes::from_slice(&env, b"red");
+    let creator_salt = creator_salt(&env);
+
+    // A creator who never reveals the answer cannot win the prize back.
+    let hidden_id = create_skill_contest(
+        &env,
+        &client,
+        &token,
+        &creator,
+        1,
+        ContestOrdering::RevealOrder,
+        &right,
+    );
+    let (entrant, _) = enter_with_answer(&env, &client, hidden_id, &wrong);
+
+    let unanswered_id = create_skill_contest(
+        &env,
+        &client,
+        &token,
+        &creator,
+        1,
+        ContestOrdering::RevealOrder,
+        &right,
+    );
+    let (guesser, guesser_salt) = enter_with_answer(&env, &client, unanswered_id, &wrong);
+    let (sleeper, _) = enter_with_answer(&env, &client, unanswered_id, &right);
+
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    client.reveal_contest_answer(&creator, &unanswered_id, &right, &creator_salt);
+    client.reveal_answer(&guesser, &unanswered_id, &wrong, &guesser_salt);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += REVEAL_WINDOW_SECONDS + 1);
+    assert_eq!(
+        client.try_reveal_contest_answer(&creator, &hidden_id, &right, &creator_salt),
+        Err(Ok(entry_error(EntryError::RevealClosed)))
+    );
+    client.finalize_contest_winners(&hidden_id);
+    assert_eq!(
+        client.get_giveaway(&hidden_id).unwrap().winners,
+        vec![&env, entrant]
+    );
+
+    client.finalize_contest_winners(&unanswered_id);
+    assert_eq!(
+        client.get_giveaway(&unanswered_id).unwrap().status,
+        GiveawayStatus::Cancelled
+    );
+    assert_eq!(token_client.balance(&creator), 1_000);
+
+    assert!(client.prune_giveaway(&unanswered_id));
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&EntryKey::AnswerCommitment(unanswered_id, sleeper.clone())));
+        assert!(!storage.has(&EntryKey::Contest(unanswered_id)));
+        assert!(!storage.has(&EntryKey::ContestAnswer(unanswered_id)));
+    });
+}
@@ -0,0 +1,245 @@
+use crate::access::check_admin;
+use crate::admin::AdminContract;
+use crate::multisig::ensure_single_key_allowed;
+use crate::types::{DataKey, Error, QueuedAction, TimelockAction, TimelockStatus};
+use crate::upgrade::UpgradeContract;
+use soroban_sdk::{contract, contractevent, contractimpl, panic_with_error, Env};
+
+/// Longest delay the admin may configure (30 days).
+const MAX_TIMELOCK_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// How long after its ETA a queued action stays executable (14 days).
+const GRACE_PERIOD_SECONDS: u64 = 14 * 24 * 60 * 60;
+
+#[contract]
+pub struct TimelockContract;
+
+#[contractevent]
+pub struct TimelockDelayChanged {
+    delay: u64,
+}
+
+#[contractevent]
+pub struct ActionQueued {
+    #[topic]
+    action_id: u64,
+    action: TimelockAction,
+    eta: u64,
+}
+
+#[contractevent]
+pub struct ActionExecuted {
+    #[topic]
+    action_id: u64,
+}
+
+#[contractevent]
+pub struct ActionCancelled {
+    #[topic]
+    action_id: u64,
+}
+
+/// Read the configured delay in seconds (0 when the timelock is off).
+pub fn timelock_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TimelockDelay)
+        .unwrap_or(0)
+}
+
+/// Panic with `Error::TimelockRequired` if a delay is set. Called by the
+/// direct entrypoints of every action in `TimelockAction`.
+pub fn ensure_not_timelocked(env: &Env) {
+    if timelock_delay(env) > 0 {
+        panic_with_error!(env, Error::TimelockRequired);
+    }
+}
+
+/// Queue `action` to become executable after the current delay.
+/// Authorization is the caller's responsibility.
+pub fn schedule(env: &Env, action: TimelockAction) -> u64 {
+    if let TimelockAction::SetDelay(delay) = action {
+        if delay > MAX_TIMELOCK_DELAY_SECONDS {
+            panic_with_error!(env, Error::InvalidDelay);
+        }
+    }
+
+    let mut action_id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TimelockCounter)
+        .unwrap_or(0);
+    action_id += 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::TimelockCounter, &action_id);
+
+    let eta = env.ledger().timestamp() + timelock_delay(env);
+    env.storage().persistent().set(
+        &DataKey::QueuedAction(action_id),
+        &QueuedAction {
+            id: action_id,
+            action: action.clone(),
+            eta,
+            status: TimelockStatus::Queued,
+        },
+    );
+
+    ActionQueued {
+        action_id,
+        action,
+        eta,
+    }
+    .publish(env);
+
+    action_id
+}
+
+#[contractimpl]
+impl TimelockContract {
+    /// Set the delay between queueing and executing timelocked actions -
+    /// callable only by Admin.
+    ///
+    /// Lengthening the delay applies immediately; shortening it has to be
+    /// queued as `TimelockAction::SetDelay` so it cannot be used to skip
+    /// the warning period.
+    ///
+    /// # Panics
+    /// * `Error::InvalidDelay` above 30 days
+    /// * `Error::TimelockRequired` if `delay` is shorter than the current one
+    pub fn set_timelock_delay(env: Env, delay: u64) {
+        check_admin(&env);
+
+        if delay > MAX_TIMELOCK_DELAY_SECONDS {
+            panic_with_error!(&env, Error::InvalidDelay);
+        }
+        if delay < timelock_delay(&env) {
+            panic_with_error!(&env, Error::TimelockRequired);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TimelockDelay, &delay);
+
+        TimelockDelayChanged { delay }.publish(&env);
+    }
+
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        timelock_delay(&env)
+    }
+
+    /// Schedule a critical action - callable only by Admin. It becomes
+    /// executable by anyone once its ETA passes.
+    ///
+    /// Withdrawals, admin handovers and upgrades are multisig-guarded too; once
+    /// a multisig is configured they reach the queue through an approved
+    /// proposal instead.
+    pub fn queue_action(env: Env, action: TimelockAction) -> u64 {
+        check_admin(&env);
+
+        if matches!(
+            action,
+            TimelockAction::Withdraw(..)
+                | TimelockAction::ProposeAdmin(_)
+                | TimelockAction::Upgrade(_)
+        ) {
+            ensure_single_key_allowed(&env);
+        }
+
+        schedule(&env, action)
+    }
+
+    /// Run a queued action whose ETA has passed. Callable by anyone.
+    ///
+    /// # Panics
+    /// * `Error::ProposalNotFound` if no action has this id
+    /// * `Error::InvalidStatus` if it was already executed or cancelled
+    /// * `Error::TimelockNotReady` before the ETA
+    /// * `Error::ProposalExpired` once the 14-day grace period has passed
+    pub fn execute_action(env: Env, action_id: u64) {
+        let mut queued = Self::load_queued(&env, action_id);
+
+        let now = env.ledger().timestamp();
+        if now < queued.eta {
+            panic_with_error!(&env, Error::TimelockNotReady);
+        }
+        if now > queued.eta + GRACE_PERIOD_SECONDS {
+            panic_with_error!(&env, Error::ProposalExpired);
+        }
+
+        // Mark executed before running the action so it cannot be replayed.
+        queued.status = TimelockStatus::Executed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::QueuedAction(action_id), &queued);
+
+        match queued.action {
+            TimelockAction::Withdraw(token, amount, to) => {
+                AdminContract::execute_withdraw(&env, token, amount, to);
+            }
+            TimelockAction::SetFee(fee_bps) => {
+                AdminContract::execute_set_fee(&env, fee_bps);
+            }
+            TimelockAction::RemoveToken(token) => {
+                AdminContract::execute_remove_token(&env, token);
+            }
+            TimelockAction::ProposeAdmin(new_admin) => {
+                AdminContract::execute_propose_admin(&env, new_admin);
+            }
+            TimelockAction::SetDelay(delay) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::TimelockDelay, &delay);
+                TimelockDelayChanged { delay }.publish(&env);
+            }
+            TimelockAction::Upgrade(new_wasm_hash) => {
+                UpgradeContract::execute_upgrade(&env, new_wasm_hash);
+            }
+            TimelockAction::SetFeeSchedule(tiers) => {
+                AdminContract::execute_set_fee_schedule(&env, tiers);
+            }
+            TimelockAction::SetFeeOverride(creator, fee_bps) => {
+                AdminContract::execute_set_fee_override(&env, creator, fee_bps);
+            }
+            TimelockAction::RemoveFeeOverride(creator) => {
+                AdminContract::execute_remove_fee_override(&env, creator);
+            }
+        }
+
+        ActionExecuted { action_id }.publish(&env);
+    }
+
+    /// Drop a queued action before it runs - callable only by Admin.
+    pub fn cancel_action(env: Env, action_id: u64) {
+        check_admin(&env);
+
+        let mut queued = Self::load_queued(&env, action_id);
+        queued.status = TimelockStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::QueuedAction(action_id), &queued);
+
+        ActionCancelled { action_id }.publish(&env);
+    }
+
+    pub fn get_queued_action(env: Env, action_id: u64) -> Option<QueuedAction> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::QueuedAction(action_id))
+    }
+}
+
+impl TimelockContract {
+    /// Load an action that is still waiting in the queue.
+    fn load_queued(env: &Env, action_id: u64) -> QueuedAction {
+        let queued: QueuedAction = env
+            .storage()
+            .persistent()
+            .get(&DataKey::QueuedAction(action_id))
+            .unwrap_or_else(|| panic_with_error!(env, Error::ProposalNotFound));
+        if queued.status != TimelockStatus::Queued {
+            panic_with_error!(env, Error::InvalidStatus);
+        }
+        queued
+    }
+}
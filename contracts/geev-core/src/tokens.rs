@@ -0,0 +1,28 @@
+use crate::ttl::extend_persistent;
+use crate::types::DataKey;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Whether `token` may back new giveaways.
+pub fn is_token_allowed(env: &Env, token: &Address) -> bool {
+    let token_key = DataKey::AllowedToken(token.clone());
+    if !env.storage().persistent().has(&token_key) {
+        return false;
+    }
+    extend_persistent(env, &token_key);
+    true
+}
+
+/// Read the enumerable list of allowlisted tokens.
+pub fn allowed_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn store_allowed_tokens(env: &Env, tokens: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AllowedTokens, tokens);
+    extend_persistent(env, &DataKey::AllowedTokens);
+}
@@ -0,0 +1,53 @@
+use crate::types::{DataKey, TtlConfig};
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Ledgers closed per day at the nominal 5-second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Entries are topped up once their remaining TTL drops below this (30 days).
+pub const DEFAULT_TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Remaining TTL an entry is topped up to (90 days).
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 90 * DAY_IN_LEDGERS;
+
+/// Ledger entries deleted per `prune_giveaway` or `prune_request` call,
+/// keeping each call within the per-transaction write limits.
+pub const PRUNE_BATCH_SIZE: u32 = 40;
+
+/// Read the admin-configured TTL thresholds, falling back to the defaults.
+pub fn ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        })
+}
+
+/// Extend the contract instance, which holds the admin, counters and config.
+pub fn extend_instance(env: &Env) {
+    let config = ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Extend a persistent entry if it exists. Missing keys are skipped so
+/// callers can pass optional per-item keys without checking them first.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    if !env.storage().persistent().has(key) {
+        return;
+    }
+    let config = ttl_config(env);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, config.threshold, config.extend_to);
+}
+
+/// Extend a live giveaway or help request together with the instance.
+/// Called whenever an entrypoint reads or writes the item.
+pub fn extend_content_ttl(env: &Env, key: &DataKey) {
+    extend_instance(env);
+    extend_persistent(env, key);
+}
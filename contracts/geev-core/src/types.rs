@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, String, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, Bytes, BytesN, String, Vec};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -36,6 +36,57 @@ pub enum Error {
     ClaimWindowNotExpired = 27,
     AlreadyClaimed = 28,
     NotWinner = 29,
+    InvalidFeeConfig = 31,
+    InsufficientSurplus = 32,
+    Paused = 33,
+    NoPendingAdmin = 34,
+    ProposalExpired = 35,
+    MissingRole = 36,
+    InvalidRole = 37,
+    // ─── Multisig Errors ───────────────────────────────────────────────────
+    MultisigRequired = 38,
+    NotSigner = 39,
+    AlreadyApproved = 40,
+    ProposalNotFound = 41,
+    ThresholdNotMet = 42,
+    InvalidThreshold = 43,
+    // ─── Timelock Errors ───────────────────────────────────────────────────
+    TimelockRequired = 44,
+    TimelockNotReady = 45,
+    InvalidDelay = 46,
+    InvalidTtlConfig = 47,
+    SelectionIncomplete = 48,
+    SelectionTooSmall = 70,
+}
+
+/// Errors from giveaway eligibility and entry. A contract error enum is
+/// limited to 50 cases, so these live apart from `Error` and continue its
+/// numbering.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EntryError {
+    InvalidEligibilityRule = 49,
+    NotAllowlisted = 50,
+    InsufficientReputation = 51,
+    ProfileRequired = 52,
+    InsufficientTokenBalance = 53,
+    InvalidMerkleProof = 54,
+    InvalidPermit = 55,
+    PermitExpired = 56,
+    PermitNonceUsed = 57,
+    NotEntered = 58,
+    AlreadyDisqualified = 59,
+    ParticipantDisqualified = 60,
+    AlreadyLeft = 61,
+    EntriesNotOpen = 62,
+    GiveawayFull = 63,
+    InvalidSchedule = 64,
+    ReserveNotMet = 65,
+    AnswerRequired = 66,
+    AnswerNotRevealed = 67,
+    AnswerMismatch = 68,
+    RevealClosed = 69,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -51,14 +102,150 @@ pub enum GiveawayStatus {
     ResolvedRefund = 6,
     UnderAppeal = 7,
     Cancelled = 8,
+    /// Winners are being drawn across several `continue_selection` calls.
+    Selecting = 9,
 }
 
+/// Entry requirements given when a giveaway is created. `allowlist` is the
+/// set of addresses that satisfy `EligibilityRule::Allowlisted`, and
+/// `merkle_root` the root for `EligibilityRule::MerkleAllowlisted`, and
+/// `permit_verifier` the ed25519 public key for `EligibilityRule::SignedPermit`. With
+/// `hold_until_draw`, `rule` is checked again for each winner at the draw,
+/// so an entrant who has since sold a gating token is passed over; the
+/// allowlist, proof and permit rules count there only if they admitted the
+/// winner on entry.
 #[derive(Clone)]
 #[contracttype]
 pub struct ParticipantVerification {
     pub allowlist: Vec<Address>,
-    pub min_reputation: u64,
-    pub uses_reputation: bool,
+    pub rule: EligibilityRule,
+    pub merkle_root: Option<BytesN<32>>,
+    pub permit_verifier: Option<BytesN<32>>,
+    pub hold_until_draw: bool,
+}
+
+/// When a giveaway takes entries and how many it needs, passed to
+/// `create_scheduled_giveaway`.
+#[derive(Clone)]
+#[contracttype]
+pub struct GiveawaySchedule {
+    /// Ledger timestamp entries open at; `None`, or a time already past,
+    /// opens them at creation.
+    pub start_time: Option<u64>,
+    /// How long entries stay open, counted from the start.
+    pub duration_seconds: u64,
+    /// Entrants accepted at most; `None` for no cap.
+    pub max_participants: Option<u32>,
+    /// Entrants needed for a draw; with fewer at `end_time` the prize goes
+    /// back to the creator. `0` for no reserve.
+    pub min_participants: u32,
+}
+
+/// What a participant submits to `enter_giveaway_with_credentials` to meet
+/// rules that need proof rather than stored state, or to answer a
+/// `SelectionMethod::SkillContest`.
+#[derive(Clone)]
+#[contracttype]
+pub struct EntryCredentials {
+    /// Sibling hashes from the participant's leaf up to the allowlist root.
+    pub merkle_proof: Vec<BytesN<32>>,
+    /// An off-chain verifier's ed25519 signature over the XDR of the
+    /// `PermitPayload` for this entry, e.g. after checking social tasks.
+    pub permit_signature: Option<BytesN<64>>,
+    /// Ledger timestamp after which the permit is refused.
+    pub permit_expiry: u64,
+    /// Any value not yet used in a permit for this giveaway.
+    pub permit_nonce: u64,
+    /// The sha256 of the entrant's `AnswerPayload`, required by skill
+    /// contests.
+    pub answer_commitment: Option<BytesN<32>>,
+}
+
+/// What an entry permit signs. `contract` is the giveaway contract, so a
+/// permit is only valid on the deployment it was issued for.
+#[derive(Clone)]
+#[contracttype]
+pub struct PermitPayload {
+    pub contract: Address,
+    pub giveaway_id: u64,
+    pub participant: Address,
+    pub expiry: u64,
+    pub nonce: u64,
+}
+
+/// What a skill-contest answer commitment hashes: the XDR of this, with
+/// `participant` the entrant, or the creator for the correct answer. The
+/// random `salt` keeps short answers from being guessed off the hash.
+#[derive(Clone)]
+#[contracttype]
+pub struct AnswerPayload {
+    pub participant: Address,
+    pub answer: Bytes,
+    pub salt: BytesN<32>,
+}
+
+/// How the winners of a skill contest are chosen among correct answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ContestOrdering {
+    /// The first correct revealers win.
+    RevealOrder = 0,
+    /// Winners are drawn at random from every correct revealer.
+    Random = 1,
+}
+
+/// The creator's side of a skill contest, passed to `create_skill_contest`.
+#[derive(Clone)]
+#[contracttype]
+pub struct SkillContest {
+    /// sha256 of the `AnswerPayload` of the correct answer, with the
+    /// creator as participant.
+    pub answer_hash: BytesN<32>,
+    pub ordering: ContestOrdering,
+}
+
+/// The correct answer of a skill contest, once the creator revealed it.
+#[derive(Clone)]
+#[contracttype]
+pub struct RevealedAnswer {
+    pub answer: Bytes,
+    /// Entrants can reveal for `REVEAL_WINDOW_SECONDS` after this.
+    pub revealed_at: u64,
+}
+
+/// A condition a participant must meet to enter a giveaway. `All` and `Any`
+/// combine rules, nested at most `MAX_RULE_DEPTH` levels deep.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum EligibilityRule {
+    /// On the giveaway's allowlist.
+    Allowlisted,
+    /// Proves a leaf under the giveaway's `AllowlistRoot`, which the creator
+    /// can rotate while the giveaway is `Active`.
+    MerkleAllowlisted,
+    /// Presents a permit signed by the giveaway's `PermitVerifier`.
+    SignedPermit,
+    /// Reputation of at least this much.
+    MinReputation(u64),
+    /// Has called `ProfileContract::set_profile`.
+    HasProfile,
+    /// Holds at least this amount of the token.
+    MinTokenBalance(Address, i128),
+    /// Every rule holds.
+    All(Vec<EligibilityRule>),
+    /// At least one rule holds.
+    Any(Vec<EligibilityRule>),
+}
+
+/// How schema v2 to v4 stored a giveaway's eligibility check, next to a
+/// `min_reputation` field. Only read by `UpgradeContract::migrate`, which
+/// turns it into an `EligibilityRule`; v1 stored the same codes as a `u32`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum VerificationType {
+    None = 0,
+    Allowlist = 1,
+    Reputation = 2,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -67,6 +254,220 @@ pub enum SelectionMethod {
     Random = 0,
     Manual = 1,
     Merit = 2,
+    /// The first `winner_count` entrants win as they enter.
+    FirstCome = 3,
+    /// Each entrant may win a remaining prize as they enter, at odds that
+    /// share the prizes out over `max_participants` entries.
+    InstantWin = 4,
+    /// Entrants commit to an answer and reveal it after `end_time`; correct
+    /// answers win.
+    SkillContest = 5,
+}
+
+/// A participant's standing in a merit giveaway, with the reputation they had
+/// when they entered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MeritEntry {
+    pub participant: Address,
+    pub reputation: u64,
+    /// Position in entry order; earlier entrants win reputation ties.
+    pub entry_index: u32,
+}
+
+/// Progress of a winner selection split across transactions by
+/// `begin_selection`, `continue_selection` and `complete_selection`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SelectionState {
+    /// Randomness fixed by `begin_selection`; unused for merit giveaways.
+    pub seed: u64,
+    /// Units of work to process: winners to draw for random giveaways,
+    /// participants to rank for merit giveaways without a leaderboard.
+    pub total: u32,
+    /// Units processed so far.
+    pub processed: u32,
+    /// Random winners drawn so far, in draw order.
+    pub winners: Vec<Address>,
+    /// Entrants of a `hold_until_draw` giveaway found no longer to meet its
+    /// rules, so later batches skip them without checking again.
+    pub passed_over: Vec<Address>,
+    /// Merit ranking built so far, best first.
+    pub leaderboard: Vec<MeritEntry>,
+    /// Keeper reward not yet paid out.
+    pub reward_remaining: i128,
+    /// Calls still due a share of the reward, `complete_selection` included.
+    pub steps_remaining: u32,
+}
+
+/// Who bears the protocol fee on a giveaway's prize.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum FeeMode {
+    /// The fee is deducted from each winner's share in `claim_prize`.
+    WinnerPays = 0,
+    /// The fee on the whole prize is charged to the creator at creation, on
+    /// top of the escrowed amount; winners receive their full gross share.
+    CreatorPays = 1,
+}
+
+/// One step of the reputation-based fee schedule. A creator whose reputation
+/// is at least `min_reputation` pays `fee_bps` instead of the base fee.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FeeTier {
+    pub min_reputation: u64,
+    pub fee_bps: u32,
+}
+
+/// Permission groups for privileged entrypoints. The admin implicitly holds
+/// every role; the others are granted to individual accounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Role {
+    /// The single stored admin; changed only through `propose_admin`.
+    Admin = 0,
+    /// Resolves appeals and toggles help request verification.
+    Moderator = 1,
+    /// Withdraws collected fees.
+    Treasurer = 2,
+    /// Marks help requests as verified.
+    Verifier = 3,
+    /// Sets the global pause level and module circuit breakers.
+    Pauser = 4,
+}
+
+/// A sensitive admin operation that, once a multisig is configured, can only
+/// run through an approved `MultisigContract` proposal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AdminAction {
+    /// `admin_withdraw(token, amount, to)`.
+    Withdraw(Address, i128, Address),
+    /// `propose_admin(new_admin)`; the new admin still has to accept.
+    ProposeAdmin(Address),
+    /// `add_token(token)`.
+    AddToken(Address),
+    /// `withdraw_fees(token)`, paid to the given recipient.
+    WithdrawFees(Address, Address),
+    /// Replace the signer set and approval threshold.
+    UpdateSigners(Vec<Address>, u32),
+    /// `upgrade(new_wasm_hash)`.
+    Upgrade(BytesN<32>),
+}
+
+/// The M-of-N signer set guarding sensitive admin operations.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MultisigConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AdminProposal {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+/// A funds- or control-affecting change that, once a timelock delay is set,
+/// must wait in the `TimelockContract` queue before it can run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TimelockAction {
+    /// `admin_withdraw(token, amount, to)`.
+    Withdraw(Address, i128, Address),
+    /// Raise the base fee to the given bps. Decreases need no timelock.
+    SetFee(u32),
+    /// `remove_token(token)`.
+    RemoveToken(Address),
+    /// `propose_admin(new_admin)`; the new admin still has to accept.
+    ProposeAdmin(Address),
+    /// Shorten the timelock delay. Increases need no timelock.
+    SetDelay(u64),
+    /// `upgrade(new_wasm_hash)`.
+    Upgrade(BytesN<32>),
+    /// `set_fee_schedule(tiers)` that charges some reputation more.
+    SetFeeSchedule(Vec<FeeTier>),
+    /// `set_fee_override(creator, fee_bps)` above the creator's current rate.
+    SetFeeOverride(Address, u32),
+    /// `remove_fee_override(creator)` that leaves the creator paying more.
+    RemoveFeeOverride(Address),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum TimelockStatus {
+    Queued = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct QueuedAction {
+    pub id: u64,
+    pub action: TimelockAction,
+    /// Earliest ledger timestamp at which the action can be executed.
+    pub eta: u64,
+    pub status: TimelockStatus,
+}
+
+/// An admin handover awaiting acceptance by `new_admin`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    pub expires_at: u64,
+}
+
+/// System-wide pause level set by a `Pauser` during an incident.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum PauseMode {
+    Unpaused = 0,
+    /// Everything is stopped except exits: prize claims and refunds.
+    WithdrawOnly = 1,
+    /// Everything is stopped, exits included.
+    Full = 2,
+}
+
+/// Independently pausable groups of entrypoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum PauseModule {
+    GiveawayCreation = 0,
+    Entries = 1,
+    /// Exit: `claim_prize` and `claim_help_request_funds`.
+    Claims = 2,
+    Donations = 3,
+    /// Exit: `claim_refund`, `cancel_giveaway` and `recover_unclaimed_prize`.
+    Refunds = 4,
+    Flagging = 5,
+}
+
+/// Storage TTL policy, in ledgers: an entry whose remaining TTL falls below
+/// `threshold` is extended back up to `extend_to` when touched.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// Snapshot of how a token's balance held by the contract compares with what
+/// the contract owes. A negative `surplus` means the contract is insolvent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Solvency {
+    pub balance: i128,
+    pub liabilities: i128,
+    pub surplus: i128,
 }
 
 #[derive(Clone)]
@@ -77,19 +478,59 @@ pub struct Giveaway {
     pub token: Address,
     pub amount: i128,
     pub title: String,
-    pub participant_count: u32,
+    /// Ledger timestamp entries open at.
+    pub start_time: u64,
     pub end_time: u64,
+    /// Entrants accepted at most, if capped.
+    pub max_participants: Option<u32>,
+    /// Entrants needed at `end_time` for a draw; see `settle_unmet_reserve`.
+    pub min_participants: u32,
     pub status: GiveawayStatus,
     pub winner_count: u32,
     pub winners: Vec<Address>,
-    pub verification_type: u32,
-    pub min_reputation: u64,
+    /// Rules a participant must all meet to enter; empty lets anyone in.
+    pub eligibility: Vec<EligibilityRule>,
+    /// Whether `eligibility` is checked again when winners are drawn.
+    pub hold_until_draw: bool,
     pub selection_method: SelectionMethod,
     /// Ledger timestamp after which unclaimed shares can be recovered.
     /// Set once, when `status` transitions to `Claimable`.
     pub claim_deadline: u64,
     /// Number of winners who have successfully called `claim_prize`.
     pub claimed_count: u32,
+    pub fee_mode: FeeMode,
+    /// Effective fee rate in basis points, fixed when the giveaway is created
+    /// from the creator's override or reputation tier at that time.
+    pub fee_bps: u32,
+}
+
+/// Why an entrant was removed from the draw by `disqualify_participant`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Disqualification {
+    /// Creator-defined code for the reason, e.g. bot activity.
+    pub reason_code: u32,
+    /// Whether the entrant has appealed through `GovernanceContract`.
+    pub appealed: bool,
+}
+
+/// What remains of a `Completed` or `Cancelled` giveaway once
+/// `prune_giveaway` has deleted its per-participant entries.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GiveawaySummary {
+    pub id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub status: GiveawayStatus,
+    pub winners: Vec<Address>,
+    pub participant_count: u32,
+    pub claimed_count: u32,
+    pub end_time: u64,
+    pub claim_deadline: u64,
+    /// Ledger timestamp of the first `prune_giveaway` call.
+    pub pruned_at: u64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -128,21 +569,39 @@ pub struct HelpRequest {
     pub expires_at: Option<u64>,
 }
 
+/// What remains of a `Closed` help request once `prune_request` has deleted
+/// its donation records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct HelpRequestSummary {
+    pub id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub goal: i128,
+    pub raised_amount: i128,
+    pub donor_count: u32,
+    pub created_at: u64,
+    /// Ledger timestamp of the first `prune_request` call.
+    pub pruned_at: u64,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     GiveawayCounter,
     Giveaway(u64),
-    ParticipantIndex(u64, u32),
-    HasEntered(u64, Address),
-    GiveawayAllowlist(u64, Address),
+    ParticipantIndex(u64, u32), // schema v3 and earlier; read only by `migrate`
+    ParticipantChunk(u64, u32), // up to `PARTICIPANT_CHUNK_SIZE` entrants, in order
+    ParticipantCount(u64),      // kept apart so entries don't rewrite `Giveaway`
+    HasEntered(u64, Address),   // `false` once the participant has left
+    GiveawayAllowlist(u64, Address), // the member's index in `AllowlistChunk`s
     HelpRequestCounter,
     HelpRequest(u64),
     Donation(u64, Address),
     Admin,
     Fee,
     CollectedFees(Address),
-    AllowedToken(Address),
+    AllowedToken(Address), // persistent; present only while the token is allowlisted
     Profile(Address),
     Username(String),
     FlagRecord(ContentType, u64, Address),
@@ -154,6 +613,69 @@ pub enum DataKey {
     // ─── Claim Lifecycle Tracking ──────────────────────────────────────────
     Claimed(u64, Address),   // whether a given winner has claimed their share
     HelpRequestClaimed(u64), // whether a help request's raised funds have been withdrawn
+    // ─── Fee Discounts ─────────────────────────────────────────────────────
+    FeeSchedule,          // reputation tiers, ascending by `min_reputation`
+    FeeOverride(Address), // per-creator fee in bps, takes precedence over tiers
+    // ─── Escrow Accounting ─────────────────────────────────────────────────
+    Liabilities(Address), // per-token sum of escrow and fees owed to users
+    Emergency,            // lifts the surplus limit on `admin_withdraw`
+    // ─── Circuit Breakers ──────────────────────────────────────────────────
+    PauseMode,                 // global pause level
+    ModulePaused(PauseModule), // per-module pause flag
+    // ─── Admin Handover ────────────────────────────────────────────────────
+    PendingAdmin, // proposed admin awaiting `accept_admin`
+    // ─── Role-Based Access ─────────────────────────────────────────────────
+    RoleMembers(Role), // accounts granted a role, besides the admin
+    // ─── Multisig ──────────────────────────────────────────────────────────
+    MultisigConfig,       // signer set and threshold, absent until configured
+    AdminProposalCounter, // last issued multisig proposal id
+    AdminProposal(u64),   // multisig proposal by id
+    // ─── Timelock ──────────────────────────────────────────────────────────
+    TimelockDelay,     // seconds between queueing and execution
+    TimelockCounter,   // last issued queued action id
+    QueuedAction(u64), // queued action by id
+    // ─── Upgrades ──────────────────────────────────────────────────────────
+    SchemaVersion,        // storage layout version, absent on v1 deployments
+    MigrationCursor,      // next giveaway id for an in-progress `migrate`
+    MigrationLiabilities, // per-token escrow tallied so far by a v1 `migrate`
+    // ─── Storage TTL ───────────────────────────────────────────────────────
+    TtlConfig,                     // admin-set TTL threshold and extension
+    GiveawayAllowlistMembers(u64), // schema v7 and earlier; read only by `migrate`
+    Donors(u64),                   // schema v7 and earlier; read only by `migrate`
+    DonorChunk(u64, u32),          // up to `LIST_CHUNK_SIZE` addresses with a `Donation`
+    DonorCount(u64),               // number of addresses across `DonorChunk`s
+    // ─── Token Allowlist ───────────────────────────────────────────────────
+    AllowedTokens, // every allowlisted token, for `list_allowed_tokens`
+    // ─── Merit Selection ───────────────────────────────────────────────────
+    MeritLeaderboard(u64), // a merit giveaway's best entrants, with runners-up
+    // ─── Chunked Selection ─────────────────────────────────────────────────
+    SelectionState(u64), // in-progress selection of a `Selecting` giveaway
+    // ─── Pruning ───────────────────────────────────────────────────────────
+    GiveawaySummary(u64),    // archived giveaway, written when pruning starts
+    HelpRequestSummary(u64), // archived help request, written when pruning starts
+}
+
+/// Storage keys for giveaway eligibility and entry management. A contract
+/// enum is limited to 50 cases, so these live apart from `DataKey`. Keys are
+/// stored by variant name, so none may share a name with a `DataKey` case.
+#[derive(Clone)]
+#[contracttype]
+pub enum EntryKey {
+    AllowlistRoot(u64),    // Merkle root for `EligibilityRule::MerkleAllowlisted`
+    PermitVerifier(u64),   // ed25519 key for `EligibilityRule::SignedPermit`
+    PermitNonce(u64, u64), // temporary; a spent permit nonce, until its expiry
+    Disqualification(u64, Address), // why an entrant was disqualified
+    DisqualifiedMembers(u64), // disqualified entrants, for counts, TTL bumps and pruning
+    WithdrawnCount(u64),   // entrants who left, still holding their slot
+    Contest(u64),          // `SkillContest` set at creation
+    ContestAnswer(u64),    // `RevealedAnswer`, once the creator revealed it
+    AnswerCommitment(u64, Address), // an entrant's answer hash, until revealed
+    CorrectRevealers(u64), // entrants who revealed the correct answer, in order
+    AllowlistChunk(u64, u32), // up to `LIST_CHUNK_SIZE` allowlisted addresses
+    AllowlistCount(u64),   // number of addresses across `AllowlistChunk`s
+    AdmittedBy(u64, Address), // `hold_until_draw`: credential rules met on entry
+    PendingFee(u64),       // `CreatorPays` fee held until winners are picked or refunded
+    EntryIndex(u64, Address), // a merit entrant's slot, to rank them by it if they rejoin
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -0,0 +1,448 @@
+use crate::access::check_admin;
+use crate::eligibility::allowlist;
+use crate::giveaway::{GiveawayContract, DEFAULT_FEE_BPS};
+use crate::multisig::ensure_single_key_allowed;
+use crate::mutual_aid::{donors, MutualAidContract};
+use crate::participants::{add_participant, participant_count};
+use crate::timelock::ensure_not_timelocked;
+use crate::tokens::{allowed_tokens, store_allowed_tokens};
+use crate::ttl::extend_persistent;
+use crate::types::{
+    DataKey, EligibilityRule, Error, FeeMode, Giveaway, HelpRequest, VerificationType,
+};
+use soroban_sdk::{
+    contract, contractevent, contractimpl, panic_with_error, Address, BytesN, Env, IntoVal, Map,
+    Symbol, TryFromVal, Val, Vec,
+};
+
+/// Storage layout written by this version of the contract.
+///
+/// * v1 - `Giveaway.verification_type` is a raw `u32`.
+/// * v2 - `Giveaway.verification_type` is a `VerificationType`, and
+///   `fee_mode` and `fee_bps` are added: `WinnerPays` at the stored `Fee`.
+/// * v3 - the token allowlist lives in persistent storage with an
+///   enumerable `AllowedTokens` list, instead of `instance()` flags.
+/// * v4 - participants live in `ParticipantChunk` entries counted by
+///   `ParticipantCount`, instead of one `ParticipantIndex` entry each and
+///   `Giveaway.participant_count`.
+/// * v5 - `Giveaway.eligibility` holds `EligibilityRule` trees, instead of
+///   `verification_type` and `min_reputation`.
+/// * v6 - `Giveaway.hold_until_draw` is added, `false` for existing entries.
+/// * v7 - `Giveaway.start_time`, `max_participants` and `min_participants`
+///   are added: open since `0`, uncapped and without a reserve.
+/// * v8 - allowlists and donor lists live in `AllowlistChunk` and
+///   `DonorChunk` entries, instead of one `GiveawayAllowlistMembers` or
+///   `Donors` entry each, and `GiveawayAllowlist` holds the member's index.
+///   The `true` flags of v1 allowlists cannot be enumerated and stay as
+///   they are.
+pub const CURRENT_SCHEMA_VERSION: u32 = 8;
+
+#[contract]
+pub struct UpgradeContract;
+
+#[contractevent]
+pub struct ContractUpgraded {
+    new_wasm_hash: BytesN<32>,
+}
+
+#[contractevent]
+pub struct SchemaMigrated {
+    from_version: u32,
+    to_version: u32,
+}
+
+/// Read the stored schema version. Deployments that predate versioning have
+/// no entry and are v1.
+pub fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+#[contractimpl]
+impl UpgradeContract {
+    /// Replace the contract code with already-uploaded wasm - callable only
+    /// by Admin. Storage is untouched; call `migrate` afterwards if the new
+    /// code bumps `CURRENT_SCHEMA_VERSION`.
+    ///
+    /// # Panics
+    /// Panics if called by non-admin address, or with
+    /// `Error::MultisigRequired` / `Error::TimelockRequired` once a multisig
+    /// or timelock guards this action
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        check_admin(&env);
+        ensure_single_key_allowed(&env);
+        ensure_not_timelocked(&env);
+
+        Self::execute_upgrade(&env, new_wasm_hash);
+    }
+
+    /// Bring stored entries up to `CURRENT_SCHEMA_VERSION` - callable only
+    /// by Admin.
+    ///
+    /// Converts at most `limit` giveaways, participant, allowlist and donor
+    /// entries per call so large deployments can migrate across several
+    /// transactions; a giveaway with many participants may itself span
+    /// several calls. Returns `true` once every entry is converted and the
+    /// schema version is bumped. Entries already in the new layout, such as
+    /// giveaways created between `upgrade` and `migrate`, are left as they
+    /// are.
+    ///
+    /// Instance storage cannot be enumerated, so the v2 to v3 step moves the
+    /// allowlist flags of the given `tokens` only: pass every token ever
+    /// added (e.g. from `TokenAdded` events) with each call.
+    ///
+    /// Help request ids are chosen by their creators and cannot be
+    /// enumerated either: pass every id posted (e.g. from
+    /// `HelpRequestPosted` events) as `requests` with each call. Their donor
+    /// lists are converted once the giveaways are done. v1 deployments
+    /// predate complete escrow tracking, so the v1 step also recomputes
+    /// `Liabilities` from the escrow still held by every giveaway, the help
+    /// requests in `requests` and the fees collected in `tokens`. Anything
+    /// left out would count as surplus `admin_withdraw` could take.
+    pub fn migrate(env: Env, limit: u32, tokens: Vec<Address>, requests: Vec<u64>) -> bool {
+        check_admin(&env);
+
+        let from_version = schema_version(&env);
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return true;
+        }
+        let seed_liabilities = from_version < 2;
+        let mut budget = limit;
+        if !Self::migrate_giveaways(&env, &mut budget, seed_liabilities) {
+            return false;
+        }
+        if !Self::migrate_donors(&env, &requests, &mut budget) {
+            return false;
+        }
+        if seed_liabilities {
+            Self::seed_liabilities(&env, &tokens, &requests);
+        }
+        if from_version < 3 {
+            Self::migrate_tokens_v2(&env, tokens);
+        }
+
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &CURRENT_SCHEMA_VERSION);
+
+        SchemaMigrated {
+            from_version,
+            to_version: CURRENT_SCHEMA_VERSION,
+        }
+        .publish(&env);
+
+        true
+    }
+
+    pub fn get_schema_version(env: Env) -> u32 {
+        schema_version(&env)
+    }
+}
+
+impl UpgradeContract {
+    pub(crate) fn execute_upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded { new_wasm_hash }.publish(env);
+    }
+
+    /// Convert more giveaways and their participant and allowlist entries to
+    /// the current layout, spending `budget`, and tally their escrow if
+    /// `seed_liabilities`. Returns `true` once the last giveaway is done.
+    fn migrate_giveaways(env: &Env, budget: &mut u32, seed_liabilities: bool) -> bool {
+        let last_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::GiveawayCounter)
+            .unwrap_or(0);
+        let mut next_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MigrationCursor)
+            .unwrap_or(1);
+
+        while next_id <= last_id && *budget > 0 {
+            if !Self::migrate_giveaway(env, next_id, budget, seed_liabilities) {
+                break;
+            }
+            next_id += 1;
+        }
+
+        // Kept past the last giveaway until `migrate` finishes, so calls
+        // still converting donor lists do not walk the giveaways again.
+        env.storage()
+            .instance()
+            .set(&DataKey::MigrationCursor, &next_id);
+        next_id > last_id
+    }
+
+    /// Bring one giveaway to the current layout, spending one unit of
+    /// `budget` per participant or allowlist member moved and, if any is
+    /// left, one on the giveaway once they all are. Returns `false` if the
+    /// budget ran out first; the entries already moved stay moved, and the
+    /// next call picks up from `ParticipantCount` and `AllowlistCount`. Any
+    /// `limit` thus makes progress.
+    ///
+    /// The entry is edited as a raw field map so that every layout can be
+    /// read. v4 and earlier store `verification_type`, as a `u32` code in v1,
+    /// and `min_reputation`; v3 and earlier also carry `participant_count` and
+    /// one `ParticipantIndex` per entrant. v5 and earlier lack
+    /// `hold_until_draw`, and v6 and earlier the schedule fields. v1 entries,
+    /// and those converted before the fee fields were backfilled, lack
+    /// `fee_mode` and `fee_bps`.
+    /// Missing (pruned) ids are skipped. Once converted, the giveaway's
+    /// unpaid escrow is tallied if `seed_liabilities`.
+    fn migrate_giveaway(
+        env: &Env,
+        giveaway_id: u64,
+        budget: &mut u32,
+        seed_liabilities: bool,
+    ) -> bool {
+        let key = DataKey::Giveaway(giveaway_id);
+        let Some(mut fields) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Map<Symbol, Val>>(&key)
+        else {
+            *budget -= 1;
+            return true;
+        };
+
+        let verification_field = Symbol::new(env, "verification_type");
+        if let Some(raw) = fields.get(verification_field.clone()) {
+            let verification_type = match u32::try_from_val(env, &raw) {
+                Ok(1) => VerificationType::Allowlist,
+                Ok(2) => VerificationType::Reputation,
+                Ok(_) => VerificationType::None,
+                Err(_) => {
+                    VerificationType::try_from_val(env, &raw).unwrap_or(VerificationType::None)
+                }
+            };
+            let reputation_field = Symbol::new(env, "min_reputation");
+            let min_reputation = fields
+                .get(reputation_field.clone())
+                .and_then(|raw| u64::try_from_val(env, &raw).ok())
+                .unwrap_or(0);
+            let mut eligibility = Vec::new(env);
+            match verification_type {
+                VerificationType::Allowlist => eligibility.push_back(EligibilityRule::Allowlisted),
+                VerificationType::Reputation => {
+                    eligibility.push_back(EligibilityRule::MinReputation(min_reputation))
+                }
+                VerificationType::None => {}
+            }
+            fields.remove(verification_field);
+            fields.remove(reputation_field);
+            fields.set(Symbol::new(env, "eligibility"), eligibility.into_val(env));
+        }
+
+        let fee_mode_field = Symbol::new(env, "fee_mode");
+        if !fields.contains_key(fee_mode_field.clone()) {
+            let fee_bps: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::Fee)
+                .unwrap_or(DEFAULT_FEE_BPS);
+            fields.set(fee_mode_field, FeeMode::WinnerPays.into_val(env));
+            fields.set(Symbol::new(env, "fee_bps"), fee_bps.into_val(env));
+        }
+
+        let hold_field = Symbol::new(env, "hold_until_draw");
+        if !fields.contains_key(hold_field.clone()) {
+            fields.set(hold_field, false.into_val(env));
+        }
+
+        let start_field = Symbol::new(env, "start_time");
+        if !fields.contains_key(start_field.clone()) {
+            fields.set(start_field, 0u64.into_val(env));
+            fields.set(
+                Symbol::new(env, "max_participants"),
+                Option::<u32>::None.into_val(env),
+            );
+            fields.set(Symbol::new(env, "min_participants"), 0u32.into_val(env));
+        }
+
+        let count_field = Symbol::new(env, "participant_count");
+        if let Some(total) = fields
+            .get(count_field.clone())
+            .and_then(|raw| u32::try_from_val(env, &raw).ok())
+        {
+            let mut moved = participant_count(env, giveaway_id);
+            while moved < total && *budget > 0 {
+                let index_key = DataKey::ParticipantIndex(giveaway_id, moved);
+                if let Some(participant) = env.storage().persistent().get::<_, Address>(&index_key)
+                {
+                    add_participant(env, giveaway_id, &participant);
+                    env.storage().persistent().remove(&index_key);
+                }
+                moved += 1;
+                *budget -= 1;
+            }
+            if moved < total {
+                env.storage().persistent().set(&key, &fields);
+                return false;
+            }
+            fields.remove(count_field);
+        }
+
+        env.storage().persistent().set(&key, &fields);
+        if !Self::migrate_allowlist(env, giveaway_id, budget) {
+            return false;
+        }
+        extend_persistent(env, &key);
+
+        if seed_liabilities {
+            let giveaway: Giveaway = env.storage().persistent().get(&key).unwrap();
+            let mut owed = Self::tallied_liabilities(env);
+            Self::tally(
+                env,
+                &mut owed,
+                giveaway.token.clone(),
+                GiveawayContract::unpaid_escrow(env, &giveaway),
+            );
+            env.storage()
+                .instance()
+                .set(&DataKey::MigrationLiabilities, &owed);
+        }
+        *budget = budget.saturating_sub(1);
+        true
+    }
+
+    /// Move a v7 `GiveawayAllowlistMembers` list into allowlist chunks,
+    /// spending one unit of `budget` per member, and point each member's
+    /// `GiveawayAllowlist` entry at its index. Returns `false` if the budget
+    /// ran out first.
+    fn migrate_allowlist(env: &Env, giveaway_id: u64, budget: &mut u32) -> bool {
+        let legacy_key = DataKey::GiveawayAllowlistMembers(giveaway_id);
+        let Some(members) = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Address>>(&legacy_key)
+        else {
+            return true;
+        };
+
+        let list = allowlist(giveaway_id);
+        let mut moved = list.len(env);
+        while moved < members.len() && *budget > 0 {
+            let member = members.get_unchecked(moved);
+            let index = list.push(env, &member);
+            let allowed_key = DataKey::GiveawayAllowlist(giveaway_id, member);
+            env.storage().persistent().set(&allowed_key, &index);
+            extend_persistent(env, &allowed_key);
+            moved += 1;
+            *budget -= 1;
+        }
+        if moved < members.len() {
+            return false;
+        }
+        env.storage().persistent().remove(&legacy_key);
+        true
+    }
+
+    /// Move the v7 `Donors` lists of `requests` into donor chunks, spending
+    /// one unit of `budget` per donor. Returns `false` if the budget ran out
+    /// first; the next call picks up from `DonorCount`.
+    fn migrate_donors(env: &Env, requests: &Vec<u64>, budget: &mut u32) -> bool {
+        for request_id in requests.iter() {
+            let legacy_key = DataKey::Donors(request_id);
+            let Some(legacy) = env
+                .storage()
+                .persistent()
+                .get::<_, Vec<Address>>(&legacy_key)
+            else {
+                continue;
+            };
+
+            let list = donors(request_id);
+            let mut moved = list.len(env);
+            while moved < legacy.len() && *budget > 0 {
+                list.push(env, &legacy.get_unchecked(moved));
+                moved += 1;
+                *budget -= 1;
+            }
+            if moved < legacy.len() {
+                return false;
+            }
+            env.storage().persistent().remove(&legacy_key);
+        }
+        true
+    }
+
+    fn tallied_liabilities(env: &Env) -> Map<Address, i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::MigrationLiabilities)
+            .unwrap_or(Map::new(env))
+    }
+
+    fn tally(env: &Env, owed: &mut Map<Address, i128>, token: Address, amount: i128) {
+        let total = owed
+            .get(token.clone())
+            .unwrap_or(0)
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
+        owed.set(token, total);
+    }
+
+    /// Overwrite `Liabilities` with the giveaway escrow tallied by
+    /// `migrate_giveaways`, plus the donations held by `requests` and the
+    /// fees collected in `tokens`. Overwriting rather than adding keeps
+    /// escrow that was already tracked from being counted twice.
+    fn seed_liabilities(env: &Env, tokens: &Vec<Address>, requests: &Vec<u64>) {
+        let mut owed = Self::tallied_liabilities(env);
+        for request_id in requests.iter() {
+            if let Some(request) = env
+                .storage()
+                .persistent()
+                .get::<_, HelpRequest>(&DataKey::HelpRequest(request_id))
+            {
+                let unpaid = MutualAidContract::unpaid_escrow(&request);
+                Self::tally(env, &mut owed, request.token, unpaid);
+            }
+        }
+        for token in tokens.iter() {
+            let fees: i128 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::CollectedFees(token.clone()))
+                .unwrap_or(0);
+            Self::tally(env, &mut owed, token, fees);
+        }
+
+        for (token, amount) in owed.iter() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Liabilities(token), &amount);
+        }
+        env.storage()
+            .instance()
+            .remove(&DataKey::MigrationLiabilities);
+    }
+
+    /// Move the v2 `instance()` allowlist flags of `tokens` to persistent
+    /// storage. Removed tokens, stored as `false` in v2, are dropped.
+    fn migrate_tokens_v2(env: &Env, tokens: Vec<Address>) {
+        let mut listed = allowed_tokens(env);
+        for token in tokens.iter() {
+            let token_key = DataKey::AllowedToken(token.clone());
+            let Some(is_allowed) = env.storage().instance().get::<_, bool>(&token_key) else {
+                continue;
+            };
+            env.storage().instance().remove(&token_key);
+
+            if is_allowed {
+                env.storage().persistent().set(&token_key, &true);
+                extend_persistent(env, &token_key);
+                if !listed.contains(&token) {
+                    listed.push_back(token);
+                }
+            }
+        }
+        store_allowed_tokens(env, &listed);
+    }
+}